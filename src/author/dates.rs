@@ -0,0 +1,445 @@
+use std::borrow::Cow;
+
+/// Markers of approximate dates, compared without the final dot
+const APPROXIMATE: &[&str] = &["ca", "c", "circa", "env", "vers", "um"];
+/// Markers of activity dates
+const FLOURISHED: &[&str] = &["fl", "floruit", "actif", "active", "tatig"];
+/// Markers of birth dates
+const BIRTH: &[&str] = &["b", "born", "ne", "née", "né", "geb", "n"];
+/// Markers of death dates
+const DEATH: &[&str] = &["d", "died", "mort", "morte", "gest", "m", "†"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// A year, possibly imprecise
+///
+/// Imprecise years (`18..`, `195?`, `19XX`) are represented by their earliest and latest possible values.
+///
+/// ```
+/// # use b_cleaner::Year;
+/// # fn main() {
+/// let year = Year::parse("18..").unwrap();
+///
+/// assert_eq!((year.earliest(), year.latest()), (1800, 1899));
+/// assert_eq!(year.exact(), None);
+/// assert_eq!(Year::parse("1950").unwrap().exact(), Some(1950));
+/// # }
+/// ```
+pub struct Year {
+    earliest: i32,
+    latest: i32
+}
+
+impl Year {
+    /// Create a new exact year
+    pub fn new(year: i32) -> Self {
+        Year {
+            earliest: year,
+            latest: year
+        }
+    }
+
+    /// Create a new imprecise year
+    pub fn between(earliest: i32, latest: i32) -> Self {
+        Year {
+            earliest: earliest.min(latest),
+            latest: earliest.max(latest)
+        }
+    }
+
+    /// Parse a year made of digits, unknown digits can be replaced by `.`, `?`, `-`, `x` or `u`
+    ///
+    /// A trailing `?` on a complete year is ignored
+    pub fn parse(input: &str) -> Option<Year> {
+        let input = input.trim();
+
+        if !input.starts_with(|c: char| c.is_ascii_digit()) {
+            return None;
+        }
+
+        let digits = input.chars().take_while(char::is_ascii_digit).count();
+        let rest = &input[digits..];
+
+        if digits > 4 {
+            return None;
+        }
+
+        let unknown = rest.chars().take_while(|c| is_unknown_digit(*c)).count();
+
+        if digits < 4 && digits + unknown == 4 {
+            let known : i32 = input[..digits].parse().ok()?;
+            let scale = 10_i32.pow(unknown as u32);
+
+            return Some(Year::between(known * scale, known * scale + scale - 1));
+        }
+
+        input[..digits].parse().ok().map(Year::new)
+    }
+
+    /// Earliest possible value of the year
+    pub fn earliest(&self) -> i32 {
+        self.earliest
+    }
+
+    /// Latest possible value of the year
+    pub fn latest(&self) -> i32 {
+        self.latest
+    }
+
+    /// The year if it's precise
+    pub fn exact(&self) -> Option<i32> {
+        if self.earliest == self.latest {
+            Some(self.earliest)
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if both years can be the same, give or take `margin` years
+    ///
+    /// ```
+    /// # use b_cleaner::Year;
+    /// # fn main() {
+    /// assert!(Year::parse("18..").unwrap().overlaps(&Year::new(1850), 0));
+    /// assert!(Year::new(1999).overlaps(&Year::new(2001), 2));
+    /// assert!(!Year::new(1999).overlaps(&Year::new(2001), 1));
+    /// # }
+    /// ```
+    pub fn overlaps(&self, other: &Year, margin: i32) -> bool {
+        self.earliest - margin <= other.latest && other.earliest - margin <= self.latest
+    }
+}
+
+pub(crate) fn is_unknown_digit(c: char) -> bool {
+    matches!(c, '.' | '?' | '-' | 'x' | 'X' | 'u' | 'U' | '_')
+}
+
+/// Returns true if a date is marked as uncertain (`1950?`, `[1950?]`)
+pub(crate) fn is_uncertain(input: &str) -> bool {
+    let digits = input.chars().filter(char::is_ascii_digit).count();
+
+    input.contains('?') && digits >= 4
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// Life dates of a person
+///
+/// Life dates are parsed from strings such as `1950-2018`, `1950-`, `-2018`, `ca. 1600-1650`, `fl. 1820`, `18..-19..` or `b. 1950`.
+///
+/// ```
+/// # use b_cleaner::{LifeDates, Year};
+/// # fn main() {
+/// let dates = LifeDates::parse("ca. 1600-1650").unwrap();
+///
+/// assert_eq!(dates.birth(), Some(Year::new(1600)));
+/// assert_eq!(dates.death(), Some(Year::new(1650)));
+/// assert!(dates.approximate());
+///
+/// let dates = LifeDates::parse("fl. 1820").unwrap();
+///
+/// assert_eq!(dates.birth(), None);
+/// assert_eq!(dates.flourished(), Some(Year::new(1820)));
+/// # }
+/// ```
+pub struct LifeDates {
+    birth: Option<Year>,
+    death: Option<Year>,
+    flourished: Option<Year>,
+    approximate: bool
+}
+
+impl LifeDates {
+    /// Parse life dates out of a string, returns None if no year is found
+    pub fn parse(input: &str) -> Option<LifeDates> {
+        LifeDates::from_tokens(&[input])
+    }
+
+    /// Parse life dates out of a list of tokens, returns None if no year is found
+    pub fn from_tokens<S: AsRef<str>>(tokens: &[S]) -> Option<LifeDates> {
+        let input = tokens.iter().map(|token| token.as_ref().trim_matches(|c| matches!(c, '(' | ')' | '[' | ']' | ','))).collect::<Vec<&str>>().join(" ");
+        let input = join_date_parts(&input.to_lowercase());
+
+        let mut dates = LifeDates::default();
+        let mut marker = Marker::None;
+
+        for word in input.split_whitespace() {
+            let keyword = word.trim_end_matches('.');
+
+            if APPROXIMATE.contains(&keyword) {
+                dates.approximate = true;
+            } else if FLOURISHED.contains(&keyword) {
+                marker = Marker::Flourished;
+            } else if BIRTH.contains(&keyword) {
+                marker = Marker::Birth;
+            } else if DEATH.contains(&keyword) {
+                marker = Marker::Death;
+            } else if word.contains(|c: char| c.is_ascii_digit()) {
+                dates.approximate |= is_uncertain(word);
+                dates.add_years(word, marker);
+            }
+        }
+
+        if dates.birth.is_none() && dates.death.is_none() && dates.flourished.is_none() {
+            return None;
+        }
+
+        Some(dates)
+    }
+
+    fn add_years(&mut self, word: &str, marker: Marker) {
+        let (start, end) = split_range(word);
+        let start = start.and_then(Year::parse);
+        let end = end.and_then(Year::parse);
+
+        match marker {
+            Marker::Flourished => {
+                self.flourished = match (start, end) {
+                    (Some(start), Some(end)) => Some(Year::between(start.earliest, end.latest)),
+                    (start, end) => start.or(end)
+                };
+            },
+            Marker::Birth => self.birth = start.or(end),
+            Marker::Death => self.death = start.or(end),
+            Marker::None => {
+                if start.is_some() {
+                    self.birth = start;
+                }
+                if end.is_some() {
+                    self.death = end;
+                }
+            }
+        }
+    }
+
+    /// Birth year
+    pub fn birth(&self) -> Option<Year> {
+        self.birth
+    }
+
+    /// Death year
+    pub fn death(&self) -> Option<Year> {
+        self.death
+    }
+
+    /// Period of activity (`fl.`)
+    pub fn flourished(&self) -> Option<Year> {
+        self.flourished
+    }
+
+    /// Returns true if the dates are approximate (`ca.`, `?`)
+    pub fn approximate(&self) -> bool {
+        self.approximate
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Marker {
+    None,
+    Birth,
+    Death,
+    Flourished
+}
+
+/// Removes white spaces inside dates (`18.. - 19 ..` becomes `18..-19..`)
+pub(crate) fn join_date_parts(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c.is_whitespace() {
+            let previous = output.chars().last();
+            let next = chars.peek().copied();
+
+            let glued = previous == Some('-')
+                || matches!(next, Some('-') | Some('?'))
+                || next == Some('.') && previous.is_some_and(|c| c.is_ascii_digit() || is_unknown_digit(c));
+
+            if glued {
+                continue;
+            }
+        }
+
+        output.push(c);
+    }
+
+    output
+}
+
+/// Splits `1950-2018` into its start and end, `-` can also be used for unknown digits (`19--`)
+pub(crate) fn split_range(word: &str) -> (Option<&str>, Option<&str>) {
+    if let Some(end) = word.strip_prefix('-') {
+        return (None, Some(end));
+    }
+
+    let separator = word.char_indices()
+        .filter(|(_, c)| *c == '-')
+        .map(|(index, _)| index)
+        .find(|index| {
+            let start = &word[..*index];
+
+            start.len() >= 4 || !start.is_empty() && start.chars().all(|c| c.is_ascii_digit()) && word[index + 1..].starts_with(|c: char| c.is_ascii_digit())
+        });
+
+    match separator {
+        Some(index) => (Some(&word[..index]), Some(&word[index + 1..]).filter(|end| !end.is_empty())),
+        None => (Some(word), None)
+    }
+}
+
+/// Returns true if a token marks a date (`ca.`, `fl.`, `b.`), single letters are only markers in lowercase as they can be initials (`B.`)
+fn is_date_marker(token: &str) -> bool {
+    let keyword = token.trim_end_matches('.');
+    let keyword = if keyword.chars().count() > 1 { keyword.to_lowercase() } else { keyword.to_string() };
+
+    [APPROXIMATE, FLOURISHED, BIRTH, DEATH].iter().any(|markers| markers.contains(&keyword.as_str()))
+}
+
+/// Returns true if a token is made of unknown digits split off a date by the tokenizer (`18..-19` followed by `..`)
+fn is_date_remainder(token: &str) -> bool {
+    !token.is_empty() && token.chars().all(|c| matches!(c, '.' | '?' | '-' | '_'))
+}
+
+/// Removes the tokens between parentheses and brackets, tokens containing digits are returned as dates
+///
+/// A group left open by a truncated field is flushed at the end of the tokens.
+///
+/// Dates outside brackets (`Doe, John, ca. 1600-1650`) are returned along with their markers and their unknown digits.
+pub(crate) fn split_off_dates<'a>(tokens: Vec<Cow<'a, str>>) -> (Vec<Cow<'a, str>>, Vec<Cow<'a, str>>) {
+    let mut name : Vec<Cow<'a, str>> = Vec::with_capacity(tokens.len());
+    let mut dates = Vec::new();
+    let mut group : Option<Vec<Cow<'a, str>>> = None;
+    let mut in_date = false;
+
+    for token in tokens {
+        let closing = token.ends_with(')') || token.ends_with(']');
+
+        match group.as_mut() {
+            Some(group) => group.push(token),
+            None if token.starts_with('(') || token.starts_with('[') => group = Some(vec![token]),
+            None if token.chars().any(|c| c.is_ascii_digit()) => {
+                let mut start = name.len();
+
+                loop {
+                    let marker = if start > 0 && name[start - 1] == "." { start - 1 } else { start };
+
+                    match marker.checked_sub(1) {
+                        Some(index) if is_date_marker(&name[index]) => start = index,
+                        _ => break
+                    }
+                }
+
+                dates.extend(name.drain(start..));
+                dates.push(token);
+                in_date = true;
+                continue;
+            },
+            None if in_date && is_date_remainder(&token) => {
+                dates.push(token);
+                continue;
+            },
+            None => name.push(token)
+        }
+
+        in_date = false;
+
+        if closing {
+            if let Some(group) = group.take() {
+                if group.iter().any(|token| token.chars().any(|c| c.is_ascii_digit())) {
+                    dates.extend(group);
+                }
+            }
+        }
+    }
+
+    // An unclosed group is kept, as dates if it holds digits (`Doe, John (1950-`) or in the name otherwise
+    if let Some(group) = group {
+        if group.iter().any(|token| token.chars().any(|c| c.is_ascii_digit())) {
+            dates.extend(group);
+        } else {
+            name.extend(group);
+        }
+    }
+
+    (name, dates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokenizer::Tokenizer;
+
+    fn years(input: &str) -> (Option<Year>, Option<Year>) {
+        let dates = LifeDates::parse(input).unwrap();
+
+        (dates.birth(), dates.death())
+    }
+
+    #[test]
+    fn test_life_dates_ranges() {
+        assert_eq!(years("1950-2018"), (Some(Year::new(1950)), Some(Year::new(2018))));
+        assert_eq!(years("1950-"), (Some(Year::new(1950)), None));
+        assert_eq!(years("-2018"), (None, Some(Year::new(2018))));
+        assert_eq!(years("18..-19.."), (Some(Year::between(1800, 1899)), Some(Year::between(1900, 1999))));
+        assert_eq!(years("b. 1950"), (Some(Year::new(1950)), None));
+        assert_eq!(years("d. 2018"), (None, Some(Year::new(2018))));
+        assert_eq!(years("1066-1087?"), (Some(Year::new(1066)), Some(Year::new(1087))));
+        assert_eq!(years("480-524"), (Some(Year::new(480)), Some(Year::new(524))));
+        assert_eq!(years("19--"), (Some(Year::between(1900, 1999)), None));
+    }
+
+    #[test]
+    fn test_life_dates_from_tokens() {
+        let tokens = ["(", "18", "..", "-", "19", "..", ")"];
+        let dates = LifeDates::from_tokens(&tokens).unwrap();
+
+        assert_eq!(dates.birth(), Some(Year::between(1800, 1899)));
+        assert_eq!(dates.death(), Some(Year::between(1900, 1999)));
+
+        assert!(LifeDates::from_tokens(&["fl", ".", "1820-1830"]).unwrap().flourished() == Some(Year::between(1820, 1830)));
+        assert!(LifeDates::from_tokens(&["1066-1087", "?"]).unwrap().approximate());
+        assert_eq!(LifeDates::from_tokens(&["(", "-", "2018", ")"]).unwrap().death(), Some(Year::new(2018)));
+        assert_eq!(LifeDates::parse("sans date"), None);
+    }
+
+    #[test]
+    fn test_split_off_dates() {
+        let split = |input: &str| {
+            let (name, dates) = split_off_dates(Tokenizer::new(input).map(Cow::Borrowed).collect());
+
+            (name.join(" "), LifeDates::from_tokens(&dates).unwrap())
+        };
+
+        let (name, dates) = split("Dupont, Jean, 18..-19..");
+        assert_eq!(name, "Dupont , Jean ,");
+        assert_eq!(dates.death(), Some(Year::between(1900, 1999)));
+
+        let (name, dates) = split("Doe, John, ca. 1600-1650");
+        assert_eq!(name, "Doe , John ,");
+        assert!(dates.approximate());
+        assert_eq!(dates.birth(), Some(Year::new(1600)));
+
+        let (name, dates) = split("Doe, John B. 1950-");
+        assert_eq!(name, "Doe , John B .");
+        assert_eq!(dates.birth(), Some(Year::new(1950)));
+    }
+
+    #[test]
+    fn test_split_off_unclosed_dates() {
+        let split = |input: &str| {
+            let (name, dates) = split_off_dates(Tokenizer::new(input).map(Cow::Borrowed).collect());
+
+            (name.join(" "), LifeDates::from_tokens(&dates))
+        };
+
+        let (name, dates) = split("Doe, John (1950-");
+        assert_eq!(name, "Doe , John");
+        assert_eq!(dates.unwrap().birth(), Some(Year::new(1950)));
+
+        let (name, dates) = split("Doe, John [1950-2018");
+        assert_eq!(name, "Doe , John");
+        assert_eq!(dates.unwrap().death(), Some(Year::new(2018)));
+
+        let (name, dates) = split("Doe, John (Jr.");
+        assert_eq!(name, "Doe , John ( Jr .");
+        assert!(dates.is_none());
+    }
+}
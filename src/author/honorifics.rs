@@ -0,0 +1,158 @@
+use std::borrow::Cow;
+
+use unidecode::unidecode;
+
+use crate::tokenizer::is_punctuation;
+
+/// Honorifics, compared in lowercase without accents and punctuation
+const HONORIFICS: &[&str] = &[
+    "dr", "doctor", "prof", "professor", "pr", "sir", "dame", "lord", "lady", "mr", "mrs", "ms", "miss", "mme", "mlle", "mgr", "msgr",
+    "rev", "revd", "abbe", "herr", "frau", "dott", "dottssa", "sig", "sra", "srta"
+];
+
+/// Name suffixes, compared in lowercase without punctuation
+const SUFFIXES: &[&str] = &["jr", "sr", "junior", "senior", "ii", "iii", "iv", "esq"];
+
+/// A word of a name, tokens between brackets and tokens containing digits (dates) are not words
+struct Word {
+    index: usize,
+    text: String,
+    segment: usize
+}
+
+/// Splits the words of a name out of its tokens, words are grouped in segments separated by commas
+fn words(tokens: &[Cow<str>]) -> Vec<Word> {
+    let mut words = Vec::with_capacity(tokens.len());
+    let mut depth = 0_usize;
+    let mut segment = 0;
+
+    for (index, token) in tokens.iter().enumerate() {
+        if token.starts_with('(') || token.starts_with('[') {
+            depth += 1;
+        }
+
+        let text = unidecode(&token.to_lowercase()).trim_matches(is_punctuation).to_string();
+
+        if depth == 0 && !text.is_empty() && !token.chars().any(|c| c.is_ascii_digit()) {
+            words.push(Word {
+                index,
+                text,
+                segment
+            });
+        }
+
+        if token.ends_with(')') || token.ends_with(']') {
+            depth = depth.saturating_sub(1);
+        }
+
+        if token.ends_with(',') {
+            segment += 1;
+        }
+    }
+
+    words
+}
+
+/// Honorifics and suffix removed from a name
+pub(crate) struct Affixes<'a> {
+    pub(crate) honorifics: Vec<Cow<'a, str>>,
+    pub(crate) suffix: Option<Cow<'a, str>>
+}
+
+/// Removes the honorifics (`Dr.`, `Prof.`, `Mme`) and the suffix (`Jr.`, `III`) out of the tokens of a name
+///
+/// Honorifics are recognized at the start of a comma separated part of the name (`Dr. John Doe`, `Doe, Dr. John`, `Doe, John, Sir`) and suffixes at its end (`John Doe Jr.`, `Doe, John, Jr.`). A word is never removed when it's the whole first part of the name (`Lord, John`), nor when it would leave the name empty. The dot following an abbreviated word is removed with the word.
+pub(crate) fn split_off_honorifics<'a>(tokens: Vec<Cow<'a, str>>) -> (Vec<Cow<'a, str>>, Affixes<'a>) {
+    let words = words(&tokens);
+    let mut honorifics = Vec::new();
+    let mut suffix = None;
+
+    let segment_len = |segment: usize| words.iter().filter(|word| word.segment == segment).count();
+    let mut remaining = words.len();
+
+    for (position, word) in words.iter().enumerate() {
+        let first = position == 0 || words[position - 1].segment != word.segment;
+        let last = words.get(position + 1).is_none_or(|next| next.segment != word.segment);
+        let removable = remaining > 1 && (word.segment > 0 || segment_len(word.segment) > 1);
+
+        if !removable {
+            continue;
+        }
+
+        let previous_is_honorific = position > 0 && honorifics.last() == Some(&words[position - 1].index);
+
+        if (first || previous_is_honorific) && HONORIFICS.contains(&word.text.as_str()) {
+            honorifics.push(word.index);
+            remaining -= 1;
+        } else if last && suffix.is_none() && SUFFIXES.contains(&word.text.as_str()) {
+            suffix = Some(word.index);
+            remaining -= 1;
+        }
+    }
+
+    let mut removed = vec![false; tokens.len()];
+
+    for index in honorifics.iter().copied().chain(suffix) {
+        removed[index] = true;
+
+        if tokens.get(index + 1).is_some_and(|token| token.as_ref() == ".") {
+            removed[index + 1] = true;
+        }
+    }
+
+    let mut name = Vec::with_capacity(tokens.len());
+    let mut affixes = Affixes {
+        honorifics: Vec::with_capacity(honorifics.len()),
+        suffix: None
+    };
+
+    for (index, token) in tokens.into_iter().enumerate() {
+        if !removed[index] {
+            name.push(token);
+        } else if honorifics.contains(&index) {
+            affixes.honorifics.push(token);
+        } else if suffix == Some(index) {
+            affixes.suffix = Some(token);
+        }
+    }
+
+    (name, affixes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokenizer::Tokenizer;
+
+    fn split(input: &str) -> (Vec<String>, Vec<String>, Option<String>) {
+        let (name, affixes) = split_off_honorifics(Tokenizer::new(input).map(Cow::Borrowed).collect());
+
+        (
+            name.into_iter().map(|token| token.to_string()).collect(),
+            affixes.honorifics.into_iter().map(|token| token.to_string()).collect(),
+            affixes.suffix.map(|token| token.to_string())
+        )
+    }
+
+    #[test]
+    fn test_honorifics_and_suffix() {
+        let (name, honorifics, suffix) = split("Prof. Dr. John Doe Jr.");
+
+        assert_eq!(name, vec!["John", "Doe"]);
+        assert_eq!(honorifics, vec!["Prof", "Dr"]);
+        assert_eq!(suffix.as_deref(), Some("Jr"));
+
+        let (name, honorifics, suffix) = split("King, Martin Luther, Jr., 1929-1968");
+
+        assert_eq!(name, vec!["King", ",", "Martin", "Luther", ",", ",", "1929-1968"]);
+        assert!(honorifics.is_empty());
+        assert_eq!(suffix.as_deref(), Some("Jr"));
+    }
+
+    #[test]
+    fn test_names_are_kept() {
+        assert_eq!(split("Lord, John").0, vec!["Lord", ",", "John"]);
+        assert_eq!(split("Sir").0, vec!["Sir"]);
+        assert_eq!(split("Senior, Jane").0, vec!["Senior", ",", "Jane"]);
+    }
+}
@@ -0,0 +1,330 @@
+use std::borrow::Cow;
+
+use unidecode::unidecode;
+
+use crate::cleaners::{AuthorCleaner, Clean};
+use crate::language::Language;
+use crate::pipeline::{Pipeline, Preset};
+use crate::tokenizer::{Tokenizer, is_punctuation};
+use crate::author::roles::starts_with_role;
+
+#[cfg(feature = "stem")]
+use rust_stemmers::Algorithm;
+
+/// Separators which always split authors
+const SEPARATORS: &[&str] = &[";", "&"];
+
+impl Language {
+    /// Conjunctions of the language used to list authors
+    ///
+    /// ```
+    /// # use b_cleaner::Language;
+    /// # fn main() {
+    /// assert_eq!(Language::German.conjunctions(), &["und"]);
+    /// # }
+    /// ```
+    pub fn conjunctions(&self) -> &'static [&'static str] {
+        match self {
+            Language::French => &["et"],
+            Language::English => &["and"],
+            Language::German => &["und"],
+            Language::Spanish => &["y", "e"],
+            Language::Italian => &["e", "ed"],
+            Language::Dutch => &["en"],
+            Language::Portuguese => &["e"],
+            Language::Arabic => &["wa"],
+            Language::Danish | Language::Norwegian => &["og"],
+            Language::Swedish => &["och"]
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+/// A struct dedicated to fields listing several authors
+///
+/// The field is split on `;`, `&` and on the conjunctions of the [languages](#method.languages) (`and`, `et`, `und`, `y`, etc.), each author is then cleaned by its own [AuthorCleaner](struct.AuthorCleaner.html).
+///
+/// Conjunctions followed by a role term (`trad. et préf.`), joining a compound surname (`Ortega y Gasset, José`) or standing as a forename (`Sheeran, Ed`) don't split authors. Corporate names containing a conjunction (`Smith and Wesson`) must be flagged with [add_corporate_names](#method.add_corporate_names) to be kept whole.
+///
+/// ```
+/// # use b_cleaner::{AuthorListCleaner, Clean};
+/// # fn main() {
+/// let mut authors = AuthorListCleaner::from_str("Doe, John ; Smith, Jane & R. Roe");
+///
+/// authors.clean();
+///
+/// let tokens : Vec<_> = authors.authors().iter().map(|author| author.tokens().clone()).collect();
+///
+/// assert_eq!(tokens, vec![vec!["doe", "john"], vec!["smith", "jane"], vec!["r", "roe"]]);
+/// # }
+/// ```
+pub struct AuthorListCleaner<'a> {
+    tokens: Vec<Cow<'a, str>>,
+    authors: Vec<AuthorCleaner<'a>>,
+    languages: Vec<Language>,
+    corporate_names: Vec<Vec<String>>
+}
+
+impl <'a>AuthorListCleaner<'a> {
+    /// Create a new AuthorListCleaner
+    ///
+    /// ```
+    /// # use b_cleaner::AuthorListCleaner;
+    /// # fn main() {
+    /// let tokens = vec!["Dupont", "et", "Durand"];
+    /// let authors = AuthorListCleaner::new(&tokens);
+    ///
+    /// assert_eq!(authors.authors().len(), 2);
+    /// # }
+    /// ```
+    pub fn new<R: AsRef<str>>(input: &'a [R]) -> Self {
+        AuthorListCleaner::with_tokens(input.iter().map(|token| Cow::Borrowed(token.as_ref())))
+    }
+
+    /// Create a new AuthorListCleaner from a raw string, the string is tokenized with the [Tokenizer](struct.Tokenizer.html)
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(input: &'a str) -> Self {
+        AuthorListCleaner::with_tokens(Tokenizer::new(input).map(Cow::Borrowed))
+    }
+
+    fn with_tokens<I: Iterator<Item = Cow<'a, str>>>(tokens: I) -> Self {
+        let mut list = AuthorListCleaner {
+            tokens: tokens.collect(),
+            authors: Vec::new(),
+            languages: Language::ALL.to_vec(),
+            corporate_names: Vec::new()
+        };
+
+        list.split();
+        list
+    }
+
+    /// Get the authors
+    pub fn authors(&self) -> &Vec<AuthorCleaner<'a>> {
+        &self.authors
+    }
+
+    /// Get the authors mutably, in order to configure them before cleaning
+    ///
+    /// Authors are split again when the [languages](#method.languages) or the [corporate names](#method.add_corporate_names) change, their configuration is lost.
+    ///
+    /// ```
+    /// # use b_cleaner::{AuthorListCleaner, AmbiguousOrder, Clean};
+    /// # fn main() {
+    /// let mut authors = AuthorListCleaner::from_str("Doe, John and Jane Roe");
+    ///
+    /// authors.authors_mut().iter_mut().for_each(|author| {
+    ///     author.canonical_order(AmbiguousOrder::ForenamesFirst);
+    /// });
+    /// authors.clean();
+    ///
+    /// assert_eq!(authors.authors()[1].tokens(), &vec!["roe", "jane"]);
+    /// # }
+    /// ```
+    pub fn authors_mut(&mut self) -> &mut Vec<AuthorCleaner<'a>> {
+        &mut self.authors
+    }
+
+    /// Split on the conjunctions of some languages only, conjunctions of all the supported languages are used by default
+    ///
+    /// ```
+    /// # use b_cleaner::{AuthorListCleaner, Language};
+    /// # fn main() {
+    /// let mut authors = AuthorListCleaner::from_str("Cortázar, Julio y Borges, Jorge Luis");
+    /// assert_eq!(authors.authors().len(), 2);
+    ///
+    /// authors.languages(&[Language::English]);
+    /// assert_eq!(authors.authors().len(), 1);
+    /// # }
+    /// ```
+    pub fn languages(&mut self, languages: &[Language]) -> &Self {
+        self.languages = languages.to_vec();
+        self.split();
+        self
+    }
+
+    /// Flag corporate names which must not be split on conjunctions
+    ///
+    /// ```
+    /// # use b_cleaner::AuthorListCleaner;
+    /// # fn main() {
+    /// let mut authors = AuthorListCleaner::from_str("Smith and Wesson ; Doe, John");
+    /// assert_eq!(authors.authors().len(), 3);
+    ///
+    /// authors.add_corporate_names(&["Smith and Wesson"]);
+    /// assert_eq!(authors.authors().len(), 2);
+    /// # }
+    /// ```
+    pub fn add_corporate_names<S: AsRef<str>>(&mut self, names: &[S]) -> &Self {
+        self.corporate_names.extend(names.iter().map(|name| Tokenizer::new(name.as_ref()).filter_map(normalize_word).collect()));
+        self.corporate_names.retain(|name: &Vec<String>| !name.is_empty());
+        self.split();
+        self
+    }
+
+    /// Clean every author with a custom [Pipeline](struct.Pipeline.html) instead of the preset one
+    pub fn clean_with(&mut self, pipeline: &Pipeline) -> &Self {
+        self.authors.iter_mut().for_each(|author| {
+            author.clean_with(pipeline);
+        });
+        self.authors.retain(|author| !author.tokens().is_empty());
+
+        self
+    }
+
+    /// Split the tokens into authors
+    fn split(&mut self) {
+        let protected = self.protected_tokens();
+        let mut authors = vec![Vec::new()];
+
+        for (index, token) in self.tokens.iter().enumerate() {
+            let author = authors.last().expect("authors can't be empty");
+            let separator = !protected[index] && (
+                SEPARATORS.contains(&token.as_ref())
+                || self.is_conjunction(token)
+                    && !is_forename(&self.tokens[..index], &self.tokens[index + 1..])
+                    && !starts_with_role(&self.tokens[index..])
+                    && !starts_with_role(&self.tokens[index + 1..])
+                    && !joins_surname(author, &self.tokens[index + 1..])
+            );
+
+            if separator {
+                authors.push(Vec::new());
+            } else {
+                authors.last_mut().expect("authors can't be empty").push(token.clone());
+            }
+        }
+
+        self.authors = authors.into_iter()
+            .filter(|author| !author.iter().all(|token| token.chars().all(is_punctuation)))
+            .map(|author| AuthorCleaner::with_tokens(author.into_iter()))
+            .collect();
+    }
+
+    /// Conjunctions are compared in lowercase, but a single uppercase letter is an initial (`Smith, J. E.`)
+    fn is_conjunction(&self, token: &str) -> bool {
+        if token.chars().count() == 1 && token.chars().all(char::is_uppercase) {
+            return false;
+        }
+
+        let token = token.to_lowercase();
+
+        self.languages.iter().any(|language| language.conjunctions().contains(&token.as_str()))
+    }
+
+    /// Marks the tokens which belong to a flagged corporate name
+    fn protected_tokens(&self) -> Vec<bool> {
+        let words : Vec<(usize, String)> = self.tokens.iter()
+            .enumerate()
+            .filter_map(|(index, token)| normalize_word(token).map(|word| (index, word)))
+            .collect();
+
+        let mut protected = vec![false; self.tokens.len()];
+
+        for start in 0..words.len() {
+            for name in &self.corporate_names {
+                let matches = name.len() <= words.len() - start && name.iter().zip(&words[start..]).all(|(part, (_, word))| part == word);
+
+                if matches {
+                    protected[words[start].0..=words[start + name.len() - 1].0].iter_mut().for_each(|protected| *protected = true);
+                }
+            }
+        }
+
+        protected
+    }
+}
+
+/// Returns true if a conjunction joins the two parts of a compound surname (`Ortega y Gasset, José`, `José Ortega y Gasset`)
+///
+/// The conjunction is followed by a single word ending the surname : either the author is a single word and a comma follows the word, or the author has several words without comma and ends with the word.
+fn joins_surname(author: &[Cow<str>], next: &[Cow<str>]) -> bool {
+    let words = author.iter().filter(|token| !token.chars().all(is_punctuation)).count();
+
+    if words == 0 || author.iter().any(|token| token.ends_with(',')) {
+        return false;
+    }
+
+    match next {
+        [word, ..] if word.ends_with(',') => words == 1,
+        [_, comma, ..] if comma == "," => words == 1,
+        [_] => words > 1,
+        [_, separator, ..] => words > 1 && SEPARATORS.contains(&separator.as_ref()),
+        _ => false
+    }
+}
+
+/// Returns true if a token read as a conjunction is a forename (`Sheeran, Ed`) : it directly follows the comma of an inverted name or ends the author
+fn is_forename(previous: &[Cow<str>], next: &[Cow<str>]) -> bool {
+    let follows_comma = previous.last().is_some_and(|token| token.ends_with(','));
+    let ends_author = next.first().is_none_or(|token| SEPARATORS.contains(&token.as_ref()));
+
+    follows_comma || ends_author
+}
+
+/// Normalizes a word to compare corporate names, punctuation marks are skipped except `&`
+fn normalize_word(token: &str) -> Option<String> {
+    if token == "&" {
+        return Some(token.to_string());
+    }
+
+    let word = unidecode(&token.to_lowercase()).trim_matches(is_punctuation).to_string();
+
+    Some(word).filter(|word| !word.is_empty())
+}
+
+impl <'a>Clean for AuthorListCleaner<'a> {
+    fn clean(&mut self) -> &Self {
+        self.clean_with(&Pipeline::preset(Preset::Author, Pipeline::author))
+    }
+
+    #[cfg(feature = "stem")]
+    fn stem(&mut self, lang: Algorithm) -> &Self {
+        self.authors.iter_mut().for_each(|author| {
+            author.stem(lang);
+        });
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn authors(input: &str) -> Vec<String> {
+        let mut authors = AuthorListCleaner::from_str(input);
+        authors.clean();
+
+        authors.authors().iter().map(|author| author.tokens().join(" ")).collect()
+    }
+
+    #[test]
+    fn test_split_authors() {
+        assert_eq!(authors("Dupont et Durand"), vec!["dupont", "durand"]);
+        assert_eq!(authors("Müller, Hans und Schmidt, Eva"), vec!["muller hans", "schmidt eva"]);
+        assert_eq!(authors("Doe, John, trad. et préf. ; Roe, Jane"), vec!["doe john", "roe jane"]);
+        assert_eq!(authors("Doe, John [et al.]"), vec!["doe john"]);
+        assert_eq!(authors("Doe, John AND Roe, Jane"), vec!["doe john", "roe jane"]);
+        assert_eq!(authors("Smith, J. E. and Roe, Jane"), vec!["smith j e", "roe jane"]);
+        assert_eq!(authors("Sheeran, Ed"), vec!["sheeran ed"]);
+        assert_eq!(authors("Smith, Ed ; Doe, Jane"), vec!["smith ed", "doe jane"]);
+    }
+
+    #[test]
+    fn test_compound_surnames() {
+        assert_eq!(authors("Ortega y Gasset, José"), vec!["ortega y gasset jose"]);
+        assert_eq!(authors("José Ortega y Gasset ; Julián Marías"), vec!["jose ortega y gasset", "julian marias"]);
+        assert_eq!(authors("Cortázar, Julio y Borges, Jorge Luis"), vec!["cortazar julio", "borges jorge luis"]);
+        assert_eq!(authors("Laurel and Hardy"), vec!["laurel", "hardy"]);
+    }
+
+    #[test]
+    fn test_corporate_names() {
+        let mut authors = AuthorListCleaner::from_str("Smith & Wesson and Doe, John");
+        authors.add_corporate_names(&["Smith & Wesson"]);
+        authors.clean();
+
+        assert_eq!(authors.authors().iter().map(|author| author.tokens().join(" ")).collect::<Vec<String>>(), vec!["smith wesson", "doe john"]);
+    }
+}
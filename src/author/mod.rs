@@ -0,0 +1,17 @@
+mod name;
+pub use name::*;
+
+mod dates;
+pub use dates::*;
+
+mod particles;
+pub use particles::*;
+
+mod roles;
+pub use roles::*;
+
+mod list;
+pub use list::*;
+
+mod honorifics;
+pub(crate) use honorifics::*;
@@ -0,0 +1,493 @@
+use std::borrow::Cow;
+
+use crate::pipeline::*;
+use crate::tokenizer::{Tokenizer, is_punctuation};
+use crate::author::dates::{LifeDates, split_off_dates};
+use crate::author::particles::{Particles, ParticlePolicy};
+use crate::author::roles::{Role, split_off_roles};
+use crate::author::honorifics::split_off_honorifics;
+
+#[cfg(feature = "config")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+/// A structured personal name
+///
+/// Names are parsed from the inverted form (`Doe, John W. (1950-2018)`) and from the direct form (`John W. Doe`). Every part of the name is cleaned as the [AuthorCleaner](struct.AuthorCleaner.html) does.
+///
+/// ```
+/// # use b_cleaner::{AuthorName, Year};
+/// # fn main() {
+/// let inverted = AuthorName::from_str("Doe, John W. (1950-2018)");
+/// let direct = AuthorName::from_str("John W. Doe");
+///
+/// assert_eq!(inverted.surname(), &vec!["doe"]);
+/// assert_eq!(inverted.forenames(), &vec!["john"]);
+/// assert_eq!(inverted.initials(), &vec!["w"]);
+/// assert_eq!(inverted.dates().unwrap().birth(), Some(Year::new(1950)));
+///
+/// assert_eq!(inverted.key(), "doe john w");
+/// assert_eq!(inverted.key(), direct.key());
+/// # }
+/// ```
+pub struct AuthorName<'a> {
+    surname: Vec<Cow<'a, str>>,
+    forenames: Vec<Cow<'a, str>>,
+    initials: Vec<Cow<'a, str>>,
+    particles: Vec<Cow<'a, str>>,
+    honorifics: Vec<Cow<'a, str>>,
+    suffix: Option<Cow<'a, str>>,
+    dates: Option<LifeDates>,
+    roles: Vec<Role>,
+    form: NameForm
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// Form of a personal name
+pub enum NameForm {
+    /// Forenames first (`John Doe`), names without comma are considered to be in direct form
+    #[default]
+    Direct,
+    /// Surname first, separated from the forenames by a comma (`Doe, John`)
+    Inverted
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "config", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "config", serde(rename_all = "snake_case"))]
+/// How to read names without comma, which are ambiguous
+///
+/// ```
+/// # use b_cleaner::{AuthorCleaner, AmbiguousOrder, Clean};
+/// # fn main() {
+/// let mut author = AuthorCleaner::from_str("DOE John");
+/// author.canonical_order(AmbiguousOrder::Uppercase);
+///
+/// author.clean();
+///
+/// assert_eq!(author.tokens(), &vec!["doe", "john"]);
+/// # }
+/// ```
+pub enum AmbiguousOrder {
+    /// Forenames come first (`John Doe`)
+    #[default]
+    ForenamesFirst,
+    /// Surname comes first (`Doe John`)
+    SurnameFirst,
+    /// Words written in uppercase are the surname (`DOE John`, `John DOE`), falls back to forenames first
+    Uppercase,
+    /// Names without comma are left in their original order
+    Keep
+}
+
+/// Options used to parse names
+#[derive(Debug, Clone, Default)]
+pub(crate) struct NameOptions {
+    pub(crate) order: AmbiguousOrder,
+    pub(crate) particles: Particles,
+    pub(crate) particle_policy: ParticlePolicy,
+    pub(crate) keep_roles: bool,
+    pub(crate) keep_honorifics: bool
+}
+
+impl <'a>AuthorName<'a> {
+    /// Parse an AuthorName out of a list of tokens, punctuation marks (`,`, `(`, `)`) should be kept
+    ///
+    /// ```
+    /// # use b_cleaner::AuthorName;
+    /// # fn main() {
+    /// let tokens = vec!["Beethoven,", "Ludwig", "van"];
+    /// let name = AuthorName::new(&tokens);
+    ///
+    /// assert_eq!(name.surname(), &vec!["beethoven"]);
+    /// assert_eq!(name.particles(), &vec!["van"]);
+    /// # }
+    /// ```
+    pub fn new<R: AsRef<str>>(input: &'a [R]) -> Self {
+        AuthorName::parse(input.iter().map(|token| Cow::Borrowed(token.as_ref())).collect(), &NameOptions::default())
+    }
+
+    /// Parse an AuthorName out of a raw string, the string is tokenized with the [Tokenizer](struct.Tokenizer.html)
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(input: &'a str) -> Self {
+        AuthorName::parse(Tokenizer::new(input).map(Cow::Borrowed).collect(), &NameOptions::default())
+    }
+
+    pub(crate) fn parse(tokens: Vec<Cow<'a, str>>, options: &NameOptions) -> Self {
+        let mut name = AuthorName::parse_raw(tokens, options);
+        name.normalize();
+
+        name
+    }
+
+    /// Parse the name without normalizing its parts
+    pub(crate) fn parse_raw(tokens: Vec<Cow<'a, str>>, options: &NameOptions) -> Self {
+        let mut name = AuthorName::default();
+
+        let tokens = if options.keep_roles {
+            tokens
+        } else {
+            let (tokens, roles) = split_off_roles(tokens);
+            name.roles = roles;
+            tokens
+        };
+
+        let tokens = if options.keep_honorifics {
+            tokens
+        } else {
+            let (tokens, affixes) = split_off_honorifics(tokens);
+            name.honorifics = affixes.honorifics;
+            name.suffix = affixes.suffix;
+            tokens
+        };
+
+        let (tokens, dates) = split_off_dates(tokens);
+        name.dates = LifeDates::from_tokens(&dates);
+
+        let mut segments = split_at_commas(options.particles.split_elisions(tokens));
+        segments.iter_mut().for_each(|segment| segment.retain(|token| !token.chars().all(is_punctuation)));
+        segments.retain(|segment| !segment.is_empty());
+
+        let mut segments = segments.into_iter();
+
+        match (segments.next(), segments.next()) {
+            (Some(surname), Some(forenames)) => {
+                name.form = NameForm::Inverted;
+                name.add_surname(surname, &options.particles);
+                name.add_forenames(forenames, &options.particles);
+
+                segments.for_each(|segment| name.add_forenames(segment, &options.particles));
+            },
+            (Some(tokens), None) => {
+                let (forenames, surname) = split_direct_name(tokens, options);
+
+                name.add_forenames(forenames, &options.particles);
+                name.add_surname(surname, &options.particles);
+            },
+            _ => ()
+        }
+
+        if options.particle_policy == ParticlePolicy::Drop {
+            name.particles.clear();
+        }
+
+        name
+    }
+
+    /// Tokens of the name in canonical order : particles, surname, forenames and initials
+    pub(crate) fn into_canonical_tokens(self) -> Vec<Cow<'a, str>> {
+        self.particles.into_iter()
+            .chain(self.surname)
+            .chain(self.forenames)
+            .chain(self.initials)
+            .collect()
+    }
+
+    /// Leading particles of the surname are moved to the particles (`de Gaulle`)
+    fn add_surname(&mut self, mut tokens: Vec<Cow<'a, str>>, particles: &Particles) {
+        let start = particle_marks(&tokens, particles).iter().position(|particle| !particle).unwrap_or(0);
+
+        self.particles.extend(tokens.drain(..start));
+        self.surname.extend(tokens);
+    }
+
+    /// Forenames are split between full forenames, initials and trailing particles (`Ludwig van`)
+    ///
+    /// Trailing particles come before the particles of the surname (`La Fontaine, Jean de`)
+    fn add_forenames(&mut self, tokens: Vec<Cow<'a, str>>, particles: &Particles) {
+        let end = particle_marks(&tokens, particles).iter().rposition(|particle| !particle).map_or(tokens.len(), |index| index + 1);
+        let mut tokens = tokens;
+        let particles = tokens.split_off(end);
+
+        tokens.into_iter().for_each(|token| if is_initial(&token) {
+            self.initials.push(token);
+        } else {
+            self.forenames.push(token);
+        });
+
+        self.particles.splice(0..0, particles);
+    }
+
+    fn normalize(&mut self) {
+        let pipeline = Pipeline::preset(Preset::AuthorName, || {
+            let mut pipeline = Pipeline::new();
+            pipeline.add_author_normalization_steps();
+            pipeline.add_step(RemoveEmpty);
+
+            pipeline
+        });
+
+        pipeline.apply(&mut self.surname);
+        pipeline.apply(&mut self.forenames);
+        pipeline.apply(&mut self.initials);
+        pipeline.apply(&mut self.particles);
+        pipeline.apply(&mut self.honorifics);
+
+        if let Some(suffix) = self.suffix.take() {
+            let mut suffix = vec![suffix];
+            pipeline.apply(&mut suffix);
+            self.suffix = suffix.pop();
+        }
+    }
+
+    /// Get the form of the name, names without comma are in direct form
+    pub fn form(&self) -> NameForm {
+        self.form
+    }
+
+    /// Get the surname
+    pub fn surname(&self) -> &Vec<Cow<'a, str>> {
+        &self.surname
+    }
+
+    /// Get the full forenames
+    pub fn forenames(&self) -> &Vec<Cow<'a, str>> {
+        &self.forenames
+    }
+
+    /// Get the initials of the forenames
+    pub fn initials(&self) -> &Vec<Cow<'a, str>> {
+        &self.initials
+    }
+
+    /// Get the particles (`de`, `van`, etc.)
+    pub fn particles(&self) -> &Vec<Cow<'a, str>> {
+        &self.particles
+    }
+
+    /// Get the honorifics (`dr`, `prof`, `mme`, etc.)
+    pub fn honorifics(&self) -> &Vec<Cow<'a, str>> {
+        &self.honorifics
+    }
+
+    /// Get the suffix (`jr`, `iii`, etc.)
+    pub fn suffix(&self) -> Option<&Cow<'a, str>> {
+        self.suffix.as_ref()
+    }
+
+    /// Get the life dates
+    pub fn dates(&self) -> Option<&LifeDates> {
+        self.dates.as_ref()
+    }
+
+    /// Get the roles (`ed.`, `trad.`, etc.) removed from the name
+    pub fn roles(&self) -> &Vec<Role> {
+        &self.roles
+    }
+
+    /// Build a surname first key : particles, surname, forenames and initials
+    pub fn key(&self) -> String {
+        self.particles.iter()
+            .chain(self.surname.iter())
+            .chain(self.forenames.iter())
+            .chain(self.initials.iter())
+            .map(|token| token.as_ref())
+            .collect::<Vec<&str>>()
+            .join(" ")
+    }
+}
+
+/// Articles starting a surname when capitalized (`Le Pen`, `La Fontaine`), compared in lowercase
+const SURNAME_ARTICLES: &[&str] = &["la", "le", "les", "l'"];
+
+/// Splits a name without comma between forenames and surname
+///
+/// A capitalized particle ending the name is the surname (`Veena Das`), a capitalized article starts the surname along with the particles before it (`Jean de La Fontaine`)
+fn split_direct_name<'a>(mut tokens: Vec<Cow<'a, str>>, options: &NameOptions) -> (Vec<Cow<'a, str>>, Vec<Cow<'a, str>>) {
+    let mut particles = particle_marks(&tokens, &options.particles);
+
+    if let (Some(last), Some(token)) = (particles.last_mut(), tokens.last()) {
+        *last = *last && !token.chars().next().is_some_and(char::is_uppercase);
+    }
+
+    match options.order {
+        AmbiguousOrder::SurnameFirst => {
+            let surname_end = particles.iter().position(|particle| !particle).map_or(1, |index| index + 1).min(tokens.len());
+            let forenames = tokens.split_off(surname_end);
+
+            (forenames, tokens)
+        },
+        AmbiguousOrder::Uppercase if tokens.iter().any(|token| is_uppercase(token)) && !tokens.iter().all(|token| is_uppercase(token)) => {
+            let end = tokens.iter().rposition(|token| is_uppercase(token)).map_or(0, |index| index + 1);
+            let start = tokens[..end].iter().zip(&particles).rposition(|(token, particle)| !is_uppercase(token) && !particle).map_or(0, |index| index + 1);
+
+            let mut surname = tokens.split_off(start);
+            let mut forenames = tokens;
+            forenames.extend(surname.split_off(end - start));
+
+            (forenames, surname)
+        },
+        _ => {
+            let mut surname_start = particles.iter()
+                .rposition(|particle| !particle)
+                .map(|last| particles[..last].iter().rposition(|particle| !particle).map_or(0, |index| index + 1))
+                .unwrap_or_else(|| tokens.len().saturating_sub(1));
+
+            while surname_start > 1 && is_surname_article(&tokens[surname_start - 1]) {
+                surname_start -= 1;
+
+                while surname_start > 1 && particles[surname_start - 1] {
+                    surname_start -= 1;
+                }
+            }
+
+            let surname = tokens.split_off(surname_start);
+
+            (tokens, surname)
+        }
+    }
+}
+
+/// A capitalized article (`Le`, `La`) starting a surname
+fn is_surname_article(token: &str) -> bool {
+    token.chars().next().is_some_and(char::is_uppercase) && SURNAME_ARTICLES.contains(&token.to_lowercase().as_str())
+}
+
+/// A word of at least two letters written in uppercase
+fn is_uppercase(token: &str) -> bool {
+    token.chars().filter(|c| c.is_alphabetic()).count() > 1 && !token.chars().any(char::is_lowercase)
+}
+
+/// Splits tokens at commas, commas can be separated tokens or attached to the end of a token
+fn split_at_commas(tokens: Vec<Cow<str>>) -> Vec<Vec<Cow<str>>> {
+    let mut segments = vec![Vec::new()];
+
+    for token in tokens {
+        if token.ends_with(',') {
+            let word = match token {
+                Cow::Borrowed(token) => Cow::Borrowed(token.trim_end_matches(',')),
+                Cow::Owned(token) => Cow::Owned(token.trim_end_matches(',').to_string())
+            };
+
+            if !word.is_empty() {
+                segments.last_mut().expect("segments can't be empty").push(word);
+            }
+
+            segments.push(Vec::new());
+        } else {
+            segments.last_mut().expect("segments can't be empty").push(token);
+        }
+    }
+
+    segments
+}
+
+/// Marks the particles of a list of tokens, known particles and lowercase words inside a name are considered as particles
+fn particle_marks(tokens: &[Cow<str>], particles: &Particles) -> Vec<bool> {
+    particles.mark(tokens).into_iter()
+        .zip(tokens)
+        .map(|(particle, token)| particle || token.chars().next().is_some_and(char::is_lowercase))
+        .collect()
+}
+
+/// A token made of single letters (`W`, `J.-P`) is an initial
+fn is_initial(token: &str) -> bool {
+    token.split(|c: char| !c.is_alphabetic()).filter(|part| !part.is_empty()).all(|part| part.chars().count() == 1)
+        && token.chars().any(char::is_alphabetic)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::author::dates::Year;
+
+    #[test]
+    fn test_direct_and_inverted_forms() {
+        let direct = AuthorName::from_str("Ludwig van Beethoven");
+        let inverted = AuthorName::from_str("Beethoven, Ludwig van");
+
+        assert_eq!(direct.key(), inverted.key());
+        assert_eq!(direct.key(), "van beethoven ludwig");
+        assert_eq!(direct.form(), NameForm::Direct);
+    }
+
+    #[test]
+    fn test_suffix_and_dates() {
+        let name = AuthorName::from_str("King, Martin Luther, Jr., 1929-1968");
+
+        assert_eq!(name.surname(), &vec!["king"]);
+        assert_eq!(name.forenames(), &vec!["martin", "luther"]);
+        assert_eq!(name.suffix().map(|suffix| suffix.as_ref()), Some("jr"));
+        assert_eq!(name.dates().and_then(|dates| dates.death()), Some(Year::new(1968)));
+
+        let name = AuthorName::from_str("Martin Luther King Jr.");
+
+        assert_eq!(name.surname(), &vec!["king"]);
+        assert_eq!(name.suffix().map(|suffix| suffix.as_ref()), Some("jr"));
+
+        let name = AuthorName::from_str("Doe, Mme Jane, Sr.");
+
+        assert_eq!(name.key(), "doe jane");
+        assert_eq!(name.honorifics(), &vec!["mme"]);
+        assert_eq!(name.suffix().map(|suffix| suffix.as_ref()), Some("sr"));
+    }
+
+    #[test]
+    fn test_compound_initials() {
+        let name = AuthorName::from_str("Sartre, J.-P.");
+
+        assert_eq!(name.initials(), &vec!["j-p"]);
+        assert!(name.forenames().is_empty());
+    }
+
+    fn options(order: AmbiguousOrder) -> NameOptions {
+        NameOptions {
+            order,
+            ..NameOptions::default()
+        }
+    }
+
+    #[test]
+    fn test_ambiguous_order() {
+        let tokens = |input| Tokenizer::new(input).map(Cow::Borrowed).collect();
+
+        assert_eq!(AuthorName::parse(tokens("Doe John W."), &options(AmbiguousOrder::SurnameFirst)).key(), "doe john w");
+        assert_eq!(AuthorName::parse(tokens("John W. Doe"), &options(AmbiguousOrder::SurnameFirst)).key(), "john doe w");
+        assert_eq!(AuthorName::parse(tokens("Charles de GAULLE"), &options(AmbiguousOrder::Uppercase)).key(), "de gaulle charles");
+        assert_eq!(AuthorName::parse(tokens("DE GAULLE Charles"), &options(AmbiguousOrder::Uppercase)).key(), "de gaulle charles");
+        assert_eq!(AuthorName::parse(tokens("John Doe"), &options(AmbiguousOrder::Uppercase)).key(), "doe john");
+        assert_eq!(AuthorName::from_str("Doe, John").form(), NameForm::Inverted);
+    }
+
+    #[test]
+    fn test_particles() {
+        let keys = ["Van der Waals, J.", "J. van der Waals", "Van Der Waals, J."].iter().map(|name| AuthorName::from_str(name).key()).collect::<Vec<String>>();
+
+        assert_eq!(keys, vec!["van der waals j"; 3]);
+        assert_eq!(AuthorName::from_str("La Fontaine, Jean de").key(), AuthorName::from_str("Jean de La Fontaine").key());
+        assert_eq!(AuthorName::from_str("Von Humboldt, Alexander").particles(), &vec!["von"]);
+
+        let options = NameOptions {
+            particle_policy: ParticlePolicy::Drop,
+            ..NameOptions::default()
+        };
+        let tokens = |input| Tokenizer::new(input).map(Cow::Borrowed).collect();
+
+        assert_eq!(AuthorName::parse(tokens("Beethoven, Ludwig van"), &options).key(), "beethoven ludwig");
+        assert_eq!(AuthorName::parse(tokens("Van Beethoven, Ludwig"), &options).key(), "beethoven ludwig");
+        assert_eq!(AuthorName::parse(tokens("d'Alembert, Jean Le Rond"), &options).key(), "alembert jean le rond");
+    }
+
+    #[test]
+    fn test_elided_and_leading_particles() {
+        let inverted = AuthorName::from_str("d'Alembert, Jean Le Rond");
+        let direct = AuthorName::from_str("Jean Le Rond d’Alembert");
+
+        assert_eq!(inverted.surname(), &vec!["alembert"]);
+        assert_eq!(inverted.particles(), &vec!["d"]);
+        assert_eq!(inverted.key(), direct.key());
+
+        let name = AuthorName::from_str("Ibn Khaldun");
+
+        assert_eq!(name.particles(), &vec!["ibn"]);
+        assert_eq!(name.surname(), &vec!["khaldun"]);
+        assert_eq!(name.key(), "ibn khaldun");
+        assert_eq!(AuthorName::from_str("Ahmad ibn Hanbal").key(), "ibn hanbal ahmad");
+    }
+
+    #[test]
+    fn test_lowercase_names() {
+        assert_eq!(AuthorName::from_str("john doe").key(), "doe john");
+        assert_eq!(AuthorName::from_str("doe, john").key(), "doe john");
+    }
+}
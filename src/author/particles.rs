@@ -0,0 +1,232 @@
+use std::borrow::Cow;
+
+use crate::language::Language;
+use crate::tokenizer::is_punctuation;
+
+#[cfg(feature = "config")]
+use serde::{Deserialize, Serialize};
+
+const FRENCH: &[&str] = &["de", "d'", "du", "des"];
+
+const ENGLISH: &[&str] = &["of"];
+
+const GERMAN: &[&str] = &["von", "vom", "von der", "von dem", "von und zu", "zu", "zum", "zur"];
+
+const SPANISH: &[&str] = &["de", "del", "de la", "de las", "de los"];
+
+const ITALIAN: &[&str] = &["da", "de", "d'", "del", "della", "dei", "degli", "di"];
+
+const DUTCH: &[&str] = &["van", "van de", "van der", "van den", "ten", "ter"];
+
+const PORTUGUESE: &[&str] = &["da", "das", "de", "do", "dos"];
+
+const ARABIC: &[&str] = &["ibn", "bin", "bint"];
+
+const SCANDINAVIAN: &[&str] = &["af", "av"];
+
+impl Language {
+    /// Bundled nobiliary particles of the language
+    ///
+    /// ```
+    /// # use b_cleaner::Language;
+    /// # fn main() {
+    /// assert!(Language::German.particles().contains(&"von der"));
+    /// # }
+    /// ```
+    pub fn particles(&self) -> &'static [&'static str] {
+        match self {
+            Language::French => FRENCH,
+            Language::English => ENGLISH,
+            Language::German => GERMAN,
+            Language::Spanish => SPANISH,
+            Language::Italian => ITALIAN,
+            Language::Dutch => DUTCH,
+            Language::Portuguese => PORTUGUESE,
+            Language::Arabic => ARABIC,
+            Language::Danish | Language::Norwegian | Language::Swedish => SCANDINAVIAN
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "config", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "config", serde(rename_all = "lowercase"))]
+/// What to do with the particles of a name
+pub enum ParticlePolicy {
+    /// Particles are filed with the surname (`van beethoven ludwig`)
+    #[default]
+    Attach,
+    /// Particles are removed (`beethoven ludwig`)
+    Drop
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A list of nobiliary particles (`de`, `von`, `van der`, etc.)
+///
+/// Particles made of several words are matched as a whole, the longest particle wins. Particles are compared in lowercase, so `Van der` is a particle even if it's capitalized. A capitalized word following a lowercase particle starts the surname (`de La Fontaine`), it's not part of the particle.
+///
+/// ```
+/// # use b_cleaner::{Particles, Language};
+/// # fn main() {
+/// let mut particles = Particles::new(&[Language::German]);
+/// particles.add_particles(&["zu"]);
+///
+/// assert!(particles.contains("Von"));
+/// assert!(particles.contains("zu"));
+/// assert!(!particles.contains("della"));
+/// # }
+/// ```
+pub struct Particles {
+    particles: Vec<Vec<String>>
+}
+
+impl Particles {
+    /// Create a list with the bundled particles of some languages
+    pub fn new(languages: &[Language]) -> Self {
+        let mut particles = Particles {
+            particles: Vec::new()
+        };
+
+        languages.iter().for_each(|language| {
+            particles.add_particles(language.particles());
+        });
+
+        particles
+    }
+
+    /// Add user supplied particles, words of a particle are separated by white spaces
+    pub fn add_particles<S: AsRef<str>>(&mut self, particles: &[S]) -> &mut Self {
+        for particle in particles {
+            let words : Vec<String> = particle.as_ref().split_whitespace().map(str::to_lowercase).collect();
+
+            if !words.is_empty() && !self.particles.contains(&words) {
+                self.particles.push(words);
+            }
+        }
+
+        self.particles.sort_by_key(|particle| std::cmp::Reverse(particle.len()));
+        self
+    }
+
+    /// Returns true if a single word is a particle
+    pub fn contains(&self, word: &str) -> bool {
+        let word = word.to_lowercase();
+
+        self.particles.iter().any(|particle| particle.len() == 1 && particle[0] == word)
+    }
+
+    /// Number of tokens of the longest particle at the start of the tokens, 0 if the tokens don't start with a particle
+    fn match_len(&self, tokens: &[Cow<str>]) -> usize {
+        let lowercase = tokens.first().and_then(|token| token.chars().next()).is_some_and(char::is_lowercase);
+
+        self.particles.iter()
+            .filter(|particle| particle.len() <= tokens.len() && particle.iter().zip(tokens).all(|(word, token)| word == &token.to_lowercase()))
+            .find(|particle| !lowercase || !tokens[1..particle.len()].iter().any(|token| token.chars().next().is_some_and(char::is_uppercase)))
+            .map_or(0, Vec::len)
+    }
+
+    /// Splits the elided particles off the following word (`d'Alembert`, `d’Annunzio`), the typographic apostrophe is replaced
+    pub(crate) fn split_elisions<'a>(&self, tokens: Vec<Cow<'a, str>>) -> Vec<Cow<'a, str>> {
+        let mut split = Vec::with_capacity(tokens.len());
+
+        for token in tokens {
+            let elision = token.char_indices()
+                .find(|(_, c)| *c == '\'' || *c == '’')
+                .map(|(index, c)| index + c.len_utf8())
+                .filter(|end| *end < token.len() && self.contains(&token[..*end].replace('’', "'")));
+
+            match (elision, token) {
+                (Some(end), Cow::Borrowed(token)) if !token[..end].ends_with('’') => split.extend([Cow::Borrowed(&token[..end]), Cow::Borrowed(&token[end..])]),
+                (Some(end), token) => split.extend([Cow::Owned(token[..end].replace('’', "'")), Cow::Owned(token[end..].to_string())]),
+                (None, token) => split.push(token)
+            }
+        }
+
+        split
+    }
+
+    /// Marks the tokens which belong to a particle
+    pub(crate) fn mark(&self, tokens: &[Cow<str>]) -> Vec<bool> {
+        let mut marks = vec![false; tokens.len()];
+        let mut index = 0;
+
+        while index < tokens.len() {
+            match self.match_len(&tokens[index..]) {
+                0 => index += 1,
+                len => {
+                    marks[index..index + len].iter_mut().for_each(|mark| *mark = true);
+                    index += len;
+                }
+            }
+        }
+
+        marks
+    }
+
+    /// Marks the particles which can be removed out of an author string, particles ending the surname (`Das, Veena`, `Veena Das`) are the surname itself and are kept
+    pub(crate) fn mark_removable(&self, tokens: &[Cow<str>]) -> Vec<bool> {
+        let mut marks = self.mark(tokens);
+        let surname_end = tokens.iter().position(|token| token.ends_with(',')).unwrap_or(tokens.len());
+
+        if let Some(last) = tokens[..surname_end].iter().rposition(|token| !token.chars().all(is_punctuation)) {
+            marks[..=last].iter_mut().rev().take_while(|mark| **mark).for_each(|mark| *mark = false);
+        }
+
+        marks
+    }
+}
+
+impl Default for Particles {
+    /// Particles of all the supported languages
+    fn default() -> Self {
+        Particles::new(&Language::ALL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multi_words_particles() {
+        let particles = Particles::default();
+        let tokens : Vec<Cow<str>> = ["Johannes", "Van", "Der", "Waals"].iter().map(|token| Cow::Borrowed(*token)).collect();
+
+        assert_eq!(particles.mark(&tokens), vec![false, true, true, false]);
+        assert!(!particles.contains("der"));
+
+        let tokens : Vec<Cow<str>> = ["Jean", "de", "La", "Fontaine"].iter().map(|token| Cow::Borrowed(*token)).collect();
+
+        assert_eq!(particles.mark(&tokens), vec![false, true, false, false]);
+    }
+
+    #[test]
+    fn test_particles_per_language() {
+        let particles = Particles::new(&[Language::French]);
+
+        assert!(!particles.contains("van") && !particles.contains("das") && !particles.contains("bin"));
+        assert!(Particles::new(&[Language::Dutch]).contains("van"));
+        assert!(Particles::new(&[Language::Portuguese]).contains("dos"));
+        assert!(Particles::default().contains("ibn"));
+    }
+
+    #[test]
+    fn test_surname_is_never_removed() {
+        let particles = Particles::default();
+        let marks = |input| particles.mark_removable(&crate::tokenizer::Tokenizer::new(input).map(Cow::Borrowed).collect::<Vec<Cow<str>>>());
+
+        assert_eq!(marks("Das, Veena"), vec![false, false, false]);
+        assert_eq!(marks("Veena Das"), vec![false, false]);
+        assert_eq!(marks("Beethoven, Ludwig van"), vec![false, false, false, true]);
+    }
+
+    #[test]
+    fn test_elided_particles() {
+        let particles = Particles::default();
+        let tokens : Vec<Cow<str>> = ["d'Alembert", "D’Annunzio", "l'Isle", "d'"].iter().map(|token| Cow::Borrowed(*token)).collect();
+        let tokens = particles.split_elisions(tokens);
+
+        assert_eq!(tokens, vec!["d'", "Alembert", "D'", "Annunzio", "l'Isle", "d'"]);
+        assert_eq!(particles.mark(&tokens), vec![true, false, true, false, false, true]);
+    }
+}
@@ -0,0 +1,325 @@
+use std::borrow::Cow;
+
+use unidecode::unidecode;
+
+use crate::tokenizer::is_punctuation;
+
+/// Role terms, compared in lowercase without accents and punctuation. Words of a term are separated by white spaces
+const ROLE_TERMS: &[(&str, Role)] = &[
+    ("ed", Role::Editor), ("eds", Role::Editor), ("edit", Role::Editor), ("editor", Role::Editor), ("editors", Role::Editor), ("edited by", Role::Editor),
+    ("editeur", Role::Editor), ("editrice", Role::Editor), ("dir", Role::Editor), ("direction", Role::Editor), ("sous la direction de", Role::Editor),
+    ("sous la dir de", Role::Editor), ("hrsg", Role::Editor), ("hg", Role::Editor), ("herausgeber", Role::Editor), ("herausgegeben von", Role::Editor),
+    ("a cura di", Role::Editor), ("cur", Role::Editor), ("curatore", Role::Editor),
+    ("trad", Role::Translator), ("traduction", Role::Translator), ("traducteur", Role::Translator), ("traductrice", Role::Translator),
+    ("traduit par", Role::Translator), ("tr", Role::Translator), ("trans", Role::Translator), ("transl", Role::Translator), ("translator", Role::Translator),
+    ("translated by", Role::Translator), ("ubers", Role::Translator), ("ubersetzer", Role::Translator), ("ubersetzt von", Role::Translator),
+    ("traductor", Role::Translator), ("traduttore", Role::Translator),
+    ("ill", Role::Illustrator), ("illus", Role::Illustrator), ("illustr", Role::Illustrator), ("illustrator", Role::Illustrator),
+    ("illustrated by", Role::Illustrator), ("illustre par", Role::Illustrator), ("illustriert von", Role::Illustrator), ("illustrations", Role::Illustrator),
+    ("illustrateur", Role::Illustrator), ("illustratrice", Role::Illustrator), ("ilustrador", Role::Illustrator), ("illustratore", Role::Illustrator),
+    ("pref", Role::WriterOfPreface), ("preface", Role::WriterOfPreface), ("prefacier", Role::WriterOfPreface), ("foreword", Role::WriterOfPreface),
+    ("vorwort", Role::WriterOfPreface), ("prologo", Role::WriterOfPreface), ("prefazione", Role::WriterOfPreface),
+    ("introd", Role::WriterOfIntroduction), ("introduction", Role::WriterOfIntroduction), ("einl", Role::WriterOfIntroduction),
+    ("einleitung", Role::WriterOfIntroduction), ("introduzione", Role::WriterOfIntroduction), ("introduccion", Role::WriterOfIntroduction),
+    ("comp", Role::Compiler), ("compiler", Role::Compiler), ("compilers", Role::Compiler), ("compiled by", Role::Compiler),
+    ("compilateur", Role::Compiler), ("compilatrice", Role::Compiler),
+    ("adapt", Role::Adapter), ("adaptation", Role::Adapter), ("adapter", Role::Adapter), ("adaptateur", Role::Adapter), ("bearb", Role::Adapter),
+    ("bearbeiter", Role::Adapter),
+    ("photogr", Role::Photographer), ("phot", Role::Photographer), ("photographer", Role::Photographer), ("photographe", Role::Photographer),
+    ("fotograf", Role::Photographer),
+    ("et al", Role::Others), ("et alii", Role::Others), ("u a", Role::Others), ("et coll", Role::Others), ("and others", Role::Others)
+];
+
+/// Words linking a role term to the name (`ill. de`, `Hrsg. von`), compared in lowercase
+const ROLE_LINKS: &[&str] = &["par", "by", "von", "de", "di", "da"];
+
+/// Words introducing the name, at the start of a statement of responsibility (`par Jean Dupont`) or after a role term and its complement (`trad. de l'anglais par`)
+const AGENT_LINKS: &[&str] = &["par", "by", "von"];
+
+/// Words allowed between a role term and the word introducing the name (`de l'anglais`)
+const MAX_COMPLEMENT_WORDS: usize = 4;
+
+/// Conjunctions joining two role terms (`trad. et préf.`)
+const CONJUNCTIONS: &[&str] = &["et", "and", "und", "y", "e"];
+
+/// Terms up to this length are only recognized when they are abbreviated (`ed.`) or written between brackets (`(ed)`), so names like `Ed` are kept
+const SHORT_TERM_MAX_LENGTH: usize = 3;
+
+/// Abbreviated terms longer than the short terms, they are only recognized when abbreviated (`edit.`), written between brackets or following the comma after the name (`Doe, John, comp`), so names like `Edit` or `Comp` are kept
+const ABBREVIATED_TERMS: &[&str] = &["edit", "hrsg", "trad", "trans", "transl", "ubers", "illus", "illustr", "pref", "introd", "einl", "comp", "adapt", "bearb", "photogr", "phot"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Role of a person in the creation of a resource
+///
+/// Roles are detected from the role terms found in author strings (`ed.`, `trad.`, `Hrsg.`, `[et al.]`, etc.).
+///
+/// ```
+/// # use b_cleaner::{AuthorName, Role};
+/// # fn main() {
+/// let name = AuthorName::from_str("Doe, John, ed. [et al.]");
+///
+/// assert_eq!(name.key(), "doe john");
+/// assert_eq!(name.roles(), &vec![Role::Editor, Role::Others]);
+/// assert_eq!(Role::Editor.relator_code(), Some("edt"));
+/// # }
+/// ```
+pub enum Role {
+    /// Editor (`ed.`, `dir.`, `Hrsg.`, `a cura di`)
+    Editor,
+    /// Translator (`trad.`, `transl.`, `Übers.`)
+    Translator,
+    /// Illustrator (`ill.`, `illus.`)
+    Illustrator,
+    /// Writer of preface (`préf.`, `foreword`)
+    WriterOfPreface,
+    /// Writer of introduction (`introd.`, `Einl.`)
+    WriterOfIntroduction,
+    /// Compiler (`comp.`, `compiler`)
+    Compiler,
+    /// Adapter (`adapt.`, `Bearb.`)
+    Adapter,
+    /// Photographer (`phot.`, `photogr.`)
+    Photographer,
+    /// Other unnamed contributors (`et al.`, `u. a.`)
+    Others
+}
+
+impl Role {
+    /// MARC relator code of the role, `Others` has no relator code
+    pub fn relator_code(&self) -> Option<&'static str> {
+        match self {
+            Role::Editor => Some("edt"),
+            Role::Translator => Some("trl"),
+            Role::Illustrator => Some("ill"),
+            Role::WriterOfPreface => Some("wpr"),
+            Role::WriterOfIntroduction => Some("win"),
+            Role::Compiler => Some("com"),
+            Role::Adapter => Some("adp"),
+            Role::Photographer => Some("pht"),
+            Role::Others => None
+        }
+    }
+}
+
+/// A word of an author string
+struct Word {
+    index: usize,
+    text: String,
+    abbreviated: bool,
+    bracketed: bool,
+    follows_name: bool
+}
+
+/// Splits the words of an author string out of its tokens
+fn words(tokens: &[Cow<str>]) -> Vec<Word> {
+    let mut words = Vec::with_capacity(tokens.len());
+    let mut depth = 0_usize;
+    let first_comma = tokens.iter().position(|token| token.ends_with(','));
+
+    for (index, token) in tokens.iter().enumerate() {
+        if token.starts_with('(') || token.starts_with('[') {
+            depth += 1;
+        }
+
+        let text = unidecode(&token.to_lowercase()).trim_matches(is_punctuation).to_string();
+
+        if !text.is_empty() {
+            words.push(Word {
+                index,
+                text,
+                abbreviated: token.ends_with('.') || tokens.get(index + 1).is_some_and(|next| next.starts_with('.')),
+                bracketed: depth > 0,
+                follows_name: index.checked_sub(1).is_some_and(|previous| tokens[previous].ends_with(',') && Some(previous) != first_comma)
+            });
+        }
+
+        if token.ends_with(')') || token.ends_with(']') {
+            depth = depth.saturating_sub(1);
+        }
+    }
+
+    words
+}
+
+/// Role term at the start of the words and its number of words
+fn match_term(words: &[Word]) -> Option<(Role, usize)> {
+    ROLE_TERMS.iter()
+        .map(|(term, role)| (term.split(' ').collect::<Vec<&str>>(), *role))
+        .filter(|(term, _)| term.len() <= words.len() && term.iter().zip(words).all(|(part, word)| *part == word.text))
+        .filter(|(term, _)| term.len() > 1 || term[0].len() > SHORT_TERM_MAX_LENGTH || words[0].abbreviated || words[0].bracketed)
+        .filter(|(term, _)| term.len() > 1 || !ABBREVIATED_TERMS.contains(&term[0]) || words[0].abbreviated || words[0].bracketed || words[0].follows_name)
+        .max_by_key(|(term, _)| term.len())
+        .map(|(term, role)| (role, term.len()))
+}
+
+/// Returns true if every word of a term of several words is a single letter (`u. a.`), such terms can be initials
+fn is_initials(words: &[Word]) -> bool {
+    words.len() > 1 && words.iter().all(|word| word.text.chars().count() == 1)
+}
+
+/// Returns true if a word stands apart from the forenames : it is bracketed or follows the name, but doesn't start the tokens or directly follow the comma of an inverted name (`Fanthorpe, U. A.`)
+fn stands_apart(tokens: &[Cow<str>], word: &Word) -> bool {
+    let first_comma = tokens.iter().position(|token| token.ends_with(','));
+
+    word.bracketed || word.index.checked_sub(1).is_some_and(|previous| Some(previous) != first_comma)
+}
+
+/// Returns true if the tokens start with a role term
+pub(crate) fn starts_with_role(tokens: &[Cow<str>]) -> bool {
+    let words = words(tokens);
+
+    words.first().is_some_and(|word| word.index == 0) && match_term(&words).is_some()
+}
+
+/// Removes the role terms out of the tokens, the roles are returned in order of appearance
+///
+/// The dot following an abbreviated term is removed with the term, as well as conjunctions between two terms.
+pub(crate) fn split_off_roles(tokens: Vec<Cow<str>>) -> (Vec<Cow<str>>, Vec<Role>) {
+    let words = words(&tokens);
+    let mut removed = vec![false; tokens.len()];
+    let mut roles = Vec::new();
+    let mut position = 0;
+    let mut previous_end = None;
+
+    while position < words.len() {
+        let term = match_term(&words[position..])
+            .filter(|(_, len)| !is_initials(&words[position..position + len]) || stands_apart(&tokens, &words[position]));
+
+        match term {
+            Some((role, len)) => {
+                let start = words[position].index;
+                let mut end = words[position + len - 1].index + 1;
+
+                if tokens.get(end).is_some_and(|token| token.as_ref() == ".") {
+                    end += 1;
+                }
+
+                removed[start..end].iter_mut().for_each(|removed| *removed = true);
+
+                if !roles.contains(&role) {
+                    roles.push(role);
+                }
+
+                position += len;
+                previous_end = Some(position);
+            },
+            None => {
+                let joins_terms = previous_end == Some(position)
+                    && CONJUNCTIONS.contains(&words[position].text.as_str())
+                    && match_term(&words[position + 1..]).is_some();
+
+                if joins_terms {
+                    removed[words[position].index] = true;
+                }
+
+                position += 1;
+            }
+        }
+    }
+
+    let mut removed = removed.into_iter();
+    let tokens = tokens.into_iter().filter(|_| !removed.next().unwrap_or(false)).collect();
+
+    (tokens, roles)
+}
+
+/// Removes the role phrase heading a statement of responsibility (`par`, `illustrated by`, `trad. de l'anglais par`), so the name is left
+///
+/// Linking words must be written in lowercase, so names (`Par`, `De Gaulle`) are kept. The elided `d'` following a role term (`trad. d'Anne Roe`) is removed as well.
+pub(crate) fn split_off_role_phrase(tokens: Vec<Cow<str>>) -> Vec<Cow<str>> {
+    let words = words(&tokens);
+    let mut position = 0;
+    let mut role = false;
+
+    while position < words.len() {
+        let term = match_term(&words[position..]).filter(|(_, len)| !is_initials(&words[position..position + len]));
+
+        if let Some((_, len)) = term {
+            position += len;
+            role = true;
+        } else if role && CONJUNCTIONS.contains(&words[position].text.as_str()) && match_term(&words[position + 1..]).is_some() {
+            position += 1;
+        } else {
+            break;
+        }
+    }
+
+    let is_link = |word: &Word, links: &[&str]| links.contains(&word.text.as_str()) && tokens[word.index] == word.text;
+    let max_offset = if role { MAX_COMPLEMENT_WORDS } else { 0 };
+
+    match words[position..].iter().take(max_offset + 1).position(|word| is_link(word, AGENT_LINKS)) {
+        Some(offset) => position += offset + 1,
+        None if role && words.get(position).is_some_and(|word| is_link(word, ROLE_LINKS)) => position += 1,
+        None => ()
+    }
+
+    let start = words.get(position).map_or(tokens.len(), |word| word.index);
+    let mut tokens : Vec<Cow<str>> = tokens.into_iter().skip(start).collect();
+
+    if let Some(first) = tokens.first_mut().filter(|_| role) {
+        let elided = first.strip_prefix("d'").or_else(|| first.strip_prefix("d’")).filter(|rest| !rest.is_empty()).map(str::to_string);
+
+        if let Some(rest) = elided {
+            *first = Cow::Owned(rest);
+        }
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokenizer::Tokenizer;
+
+    fn roles(input: &str) -> (Vec<String>, Vec<Role>) {
+        let (tokens, roles) = split_off_roles(Tokenizer::new(input).map(Cow::Borrowed).collect());
+
+        (tokens.into_iter().map(|token| token.to_string()).collect(), roles)
+    }
+
+    #[test]
+    fn test_role_terms() {
+        assert_eq!(roles("Dupont, Jean (dir.)"), (vec!["Dupont".into(), ",".into(), "Jean".into(), "(".into(), ")".into()], vec![Role::Editor]));
+        assert_eq!(roles("Müller, Hans, Hrsg.").1, vec![Role::Editor]);
+        assert_eq!(roles("Doe, John, trad. et préf."), (vec!["Doe".into(), ",".into(), "John".into(), ",".into()], vec![Role::Translator, Role::WriterOfPreface]));
+        assert_eq!(roles("Rossi, Mario, a cura di").1, vec![Role::Editor]);
+        assert_eq!(roles("Smith, Jane, compiler").1, vec![Role::Compiler]);
+    }
+
+    #[test]
+    fn test_abbreviated_terms_need_a_mark() {
+        assert_eq!(roles("Nagy, Edit"), (vec!["Nagy".into(), ",".into(), "Edit".into()], vec![]));
+        assert_eq!(roles("Comp, Jean"), (vec!["Comp".into(), ",".into(), "Jean".into()], vec![]));
+        assert_eq!(roles("Nagy, Edit (edit.)").1, vec![Role::Editor]);
+        assert_eq!(roles("Doe, John, comp").1, vec![Role::Compiler]);
+    }
+
+    #[test]
+    fn test_short_names_are_kept() {
+        assert_eq!(roles("Sheeran, Ed"), (vec!["Sheeran".into(), ",".into(), "Ed".into()], vec![]));
+        assert_eq!(roles("Ed Sheeran").1, vec![]);
+    }
+
+    #[test]
+    fn test_initials_are_kept() {
+        assert_eq!(roles("Fanthorpe, U. A."), (vec!["Fanthorpe".into(), ",".into(), "U".into(), ".".into(), "A".into(), ".".into()], vec![]));
+        assert_eq!(roles("U. A. Fanthorpe").1, vec![]);
+        assert_eq!(roles("Müller, Hans u. a.").1, vec![Role::Others]);
+        assert_eq!(roles("Müller, Hans, u. a.").1, vec![Role::Others]);
+        assert_eq!(roles("Müller, Hans [u. a.]").1, vec![Role::Others]);
+    }
+
+    #[test]
+    fn test_role_phrase() {
+        let phrase = |input| split_off_role_phrase(Tokenizer::new(input).map(Cow::Borrowed).collect()).join(" ");
+
+        assert_eq!(phrase("par Jean Dupont"), "Jean Dupont");
+        assert_eq!(phrase("trad. de l'anglais par Marie Roe"), "Marie Roe");
+        assert_eq!(phrase("illustrated by Jane Doe"), "Jane Doe");
+        assert_eq!(phrase("Hrsg. von Hans Müller"), "Hans Müller");
+        assert_eq!(phrase("trad. et préf. d'Anne Roe"), "Anne Roe");
+        assert_eq!(phrase("De Gaulle"), "De Gaulle");
+        assert_eq!(phrase("U. A. Fanthorpe"), "U . A . Fanthorpe");
+    }
+}
@@ -1,10 +1,18 @@
-#[cfg(feature = "stem")]
-pub use rust_stemmers::Algorithm;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
 
 use crate::cleaners::*;
+use crate::config::Profile;
 use pyo3::prelude::*;
+use pyo3::exceptions::ValueError;
 use pyo3::wrap_pyfunction;
 
+thread_local! {
+    /// Profiles loaded by path, a profile file is read and its pipeline built once
+    static PROFILES: RefCell<HashMap<String, Rc<Profile>>> = RefCell::new(HashMap::new());
+}
+
 #[pyfunction]
 fn clean_title(input: Vec<&str>) -> PyResult<Vec<String>> {
     let mut title = TitleCleaner::new(&input);
@@ -21,10 +29,33 @@ fn clean_author(input: Vec<&str>) -> PyResult<Vec<String>> {
     Ok(author.tokens().into_iter().map(|e| e.to_string()).collect::<Vec<String>>())
 }
 
+/// Clean tokens with a TOML or JSON profile, tokens are cleaned by the cleaner the profile is based on and its options
+///
+/// Returns the cleaned tokens and the subtitle, the subtitle is empty unless the profile keeps it. The profile is loaded on first use, later changes of the file are not taken into account.
+#[pyfunction]
+fn clean_with_profile(input: Vec<&str>, profile: &str) -> PyResult<(Vec<String>, Vec<String>)> {
+    let profile = match PROFILES.with(|profiles| profiles.borrow().get(profile).cloned()) {
+        Some(profile) => profile,
+        None => {
+            let loaded = Rc::new(Profile::from_file(profile).map_err(|e| ValueError::py_err(e.to_string()))?);
+            PROFILES.with(|profiles| profiles.borrow_mut().insert(profile.to_string(), Rc::clone(&loaded)));
+            loaded
+        }
+    };
+
+    let record = profile.clean(&input);
+
+    Ok((
+        record.tokens.into_iter().map(|e| e.to_string()).collect::<Vec<String>>(),
+        record.subtitle.into_iter().map(|e| e.to_string()).collect::<Vec<String>>()
+    ))
+}
+
 #[pymodule]
 fn b_cleaner(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(clean_title))?;
     m.add_wrapped(wrap_pyfunction!(clean_author))?;
+    m.add_wrapped(wrap_pyfunction!(clean_with_profile))?;
     
     Ok(())
-}
\ No newline at end of file
+}
@@ -1,13 +1,18 @@
 #![allow(dead_code)]
 
 use std::borrow::Cow;
+use std::rc::Rc;
 use unidecode::unidecode;
 
-#[cfg(feature = "html")]
-use htmlescape;
+use crate::tokenizer::Tokenizer;
+use crate::pipeline::*;
+use crate::language::Language;
+use crate::stopwords::RemoveStopwords;
+use crate::numbering::{Numbering, split_off_numbering};
+use crate::author::{AuthorName, AmbiguousOrder, NameForm, NameOptions, Particles, ParticlePolicy, LifeDates, Role, split_off_dates, split_off_roles, split_off_honorifics};
 
 #[cfg(feature = "stem")]
-use rust_stemmers::{Algorithm, Stemmer};
+use rust_stemmers::Algorithm;
 
 /// A trait used to clean and stem data
 /// 
@@ -28,11 +33,12 @@ pub trait Clean {
     #[cfg(feature = "stem")]
     /// ```
     /// # fn main () {
-    /// use b_cleaner::{Clean, TitleCleaner};
+    /// use b_cleaner::{Algorithm, Clean, TitleCleaner};
     /// 
-    /// let mut title_cleaner = TitleCleaner::new(&vec!["lorem", "impsum", "dolor", "sit"]);
+    /// let tokens = vec!["lorem", "impsum", "dolor", "sit"];
+    /// let mut title_cleaner = TitleCleaner::new(&tokens);
     /// 
-    /// title_cleaner.stem();
+    /// title_cleaner.stem(Algorithm::French);
     /// # }
     /// ```
     fn stem(&mut self, lang: Algorithm) -> &Self;
@@ -41,20 +47,23 @@ pub trait Clean {
 #[derive(Debug, Clone)]
 /// A struct dedicated to text cleaning
 /// 
-/// Cleaning process is made in this specific order :
+/// Cleaning process is made by the [text pipeline](struct.Pipeline.html#method.text), in this specific order :
 /// * tokens smaller than three chars are removed 
 /// * HTML entities are decoded (html features)
 /// * tokens are transformed to lowercase
 /// * tokens are unidecoded (accentued chars are replaced by their ASCII equivalent)
 /// * non ASCII char are removed
-/// * punctuation and digit are removed
+/// * punctuation and digit are removed, digits can be kept (see [numbers](#method.numbers))
 /// * tokens are trimed (extra white space at the begining and end of each token are removed)
 /// * empty tokens are removed
 /// 
 /// Additionally token can be stemmed, howerver stemming implies huge performance downside.
 pub struct TextCleaner<'a> {
     tokens: Vec<Cow<'a, str>>,
-    token_min_lenght: usize
+    token_min_lenght: usize,
+    stopwords: Option<RemoveStopwords>,
+    roman_numerals: Option<RomanNumerals>,
+    numbers: NumericPolicy
 }
 
 /// ```
@@ -80,12 +89,33 @@ impl <'a>TextCleaner<'a> {
     /// # }
     /// ```
     pub fn new<R: AsRef<str>>(input: &'a [R]) -> Self {
-        let mut tokens : Vec<Cow<'a, str>> = Vec::with_capacity(input.len());
-        tokens = input.iter().map(|token|Cow::Borrowed(token.as_ref())).collect();
+        TextCleaner::with_tokens(input.iter().map(|token|Cow::Borrowed(token.as_ref())).collect())
+    }
+
+    /// Create a new TextCleaner from a raw string
+    /// 
+    /// The input is split into tokens by the [Tokenizer](struct.Tokenizer.html), tokens are borrowed from the input.
+    /// 
+    /// ```
+    /// # use b_cleaner::{TextCleaner, Clean};
+    /// # fn main() {   
+    /// let mut text_cleaner = TextCleaner::from_str("Lorem ipsum dolor: sit amet");
+    /// 
+    /// assert_eq!(text_cleaner.tokens(), &vec!["Lorem", "ipsum", "dolor", ":", "sit", "amet"]);
+    /// # }
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(input: &'a str) -> Self {
+        TextCleaner::with_tokens(Tokenizer::new(input).map(Cow::Borrowed).collect())
+    }
 
+    pub(crate) fn with_tokens(tokens: Vec<Cow<'a, str>>) -> Self {
         TextCleaner {
-            tokens: tokens,
-            token_min_lenght: 3
+            tokens,
+            token_min_lenght: 3,
+            stopwords: None,
+            roman_numerals: None,
+            numbers: NumericPolicy::Drop
         }
     }
 
@@ -122,67 +152,156 @@ impl <'a>TextCleaner<'a> {
         self.token_min_lenght = thresold;
         self
     }
-}
 
-impl <'a>Clean for TextCleaner<'a> {
-    fn clean(&mut self) -> &Self {
-        let token_min_lenght = self.token_min_lenght;
+    /// Remove the stopwords of some languages, see [RemoveStopwords](struct.RemoveStopwords.html)
+    /// 
+    /// ```
+    /// # use b_cleaner::{TextCleaner, Clean, Language};
+    /// # fn main() {   
+    /// let mut text_cleaner = TextCleaner::from_str("A story about the warriors");
+    /// text_cleaner.stopwords(&[Language::English, Language::French]);
+    /// 
+    /// text_cleaner.clean();
+    /// 
+    /// assert_eq!(text_cleaner.tokens(), &vec!["story", "warriors"]);
+    /// # }
+    /// ```
+    pub fn stopwords(&mut self, languages: &[Language]) -> &Self {
+        self.stopwords = Some(RemoveStopwords::new(languages));
+        self
+    }
+
+    /// Add user supplied stopwords
+    pub fn add_stopwords<S: AsRef<str>>(&mut self, words: &[S]) -> &Self {
+        self.stopwords.get_or_insert_with(RemoveStopwords::default).add_words(words);
+        self
+    }
+
+    /// Convert or remove Roman numerals, see [RomanNumerals](enum.RomanNumerals.html). Roman numerals are kept as words by default
+    /// 
+    /// Converted numerals are kept whatever the [numbers](#method.numbers) policy, digits are kept standalone instead of dropped.
+    /// 
+    /// ```
+    /// # use b_cleaner::{TextCleaner, Clean, RomanNumerals};
+    /// # fn main() {   
+    /// let mut text_cleaner = TextCleaner::from_str("Louis XVIII, Charles VIII");
+    /// text_cleaner.roman_numerals(RomanNumerals::Remove);
+    /// 
+    /// text_cleaner.clean();
+    /// 
+    /// assert_eq!(text_cleaner.tokens(), &vec!["louis", "charles"]);
+    /// # }
+    /// ```
+    pub fn roman_numerals(&mut self, roman_numerals: RomanNumerals) -> &Self {
+        self.roman_numerals = Some(roman_numerals);
+        self
+    }
+
+    /// Set what to do with numbers, see [NumericPolicy](enum.NumericPolicy.html). Digits are removed by default
+    /// 
+    /// Numbers are kept whatever their length unless they are dropped.
+    /// 
+    /// ```
+    /// # use b_cleaner::{TextCleaner, Clean, NumericPolicy};
+    /// # fn main() {   
+    /// let mut text_cleaner = TextCleaner::from_str("The 39 steps");
+    /// text_cleaner.numbers(NumericPolicy::KeepStandalone);
+    /// 
+    /// text_cleaner.clean();
+    /// 
+    /// assert_eq!(text_cleaner.tokens(), &vec!["39", "steps"]);
+    /// # }
+    /// ```
+    pub fn numbers(&mut self, policy: NumericPolicy) -> &Self {
+        self.numbers = policy;
+        self
+    }
 
-        self.tokens.retain(|token| !(token.len() <= token_min_lenght));
+    /// Get the pipeline used to clean tokens, Roman numerals are handled first and stopwords are removed after the normalization steps, elided stopwords (`l'`, `dell'`) being split off before the punctuation is removed
+    pub fn pipeline(&self) -> Pipeline {
+        let mut pipeline = Pipeline::text(self.token_min_lenght);
+        pipeline.set_numeric_policy(self.numbers.with_roman_numerals(self.roman_numerals), self.token_min_lenght);
 
-        self.tokens.iter_mut().for_each(|mut token| {
-            #[cfg(feature = "html")]
-            decode_token_html_entities(&mut token);
+        if let Some(roman_numerals) = self.roman_numerals {
+            pipeline.insert_step(0, roman_numerals);
+        }
 
-            token_to_lowercase(&mut token);
-            unidecode_token(&mut token);
-            remove_token_non_ascii_chars(&mut token);
-            remove_token_digit_and_punctuation(&mut token);
-            token_trim(&mut token);
-        });
+        if let Some(stopwords) = &self.stopwords {
+            pipeline.add_stopwords(stopwords);
+        }
 
-        self.tokens.retain(|token| !token.is_empty());
-        self.tokens.shrink_to_fit();
+        pipeline
+    }
+
+    /// The pipeline used while cleaning, shared between the cleaners unless stopwords are removed
+    fn shared_pipeline(&self) -> Rc<Pipeline> {
+        match self.stopwords {
+            Some(_) => Rc::new(self.pipeline()),
+            None => Pipeline::preset(Preset::Text(self.token_min_lenght, self.numbers, self.roman_numerals), || self.pipeline())
+        }
+    }
 
+    /// Clean the tokens with a custom [Pipeline](struct.Pipeline.html) instead of the preset one
+    /// 
+    /// ```
+    /// # use b_cleaner::{TextCleaner, Pipeline, Lowercase};
+    /// # fn main() {   
+    /// let mut pipeline = Pipeline::new();
+    /// pipeline.add_step(Lowercase);
+    /// 
+    /// let mut text_cleaner = TextCleaner::from_str("Lorem ipsum");
+    /// text_cleaner.clean_with(&pipeline);
+    /// 
+    /// assert_eq!(text_cleaner.tokens(), &vec!["lorem", "ipsum"]);
+    /// # }
+    /// ```
+    pub fn clean_with(&mut self, pipeline: &Pipeline) -> &Self {
+        pipeline.apply(&mut self.tokens);
         self
     }
+}
 
-    #[cfg(feature = "stem")]
-    fn stem(&mut self, lang: Algorithm) -> &Self {
-        let stemmer = Stemmer::create(lang);
+impl <'a>Clean for TextCleaner<'a> {
+    fn clean(&mut self) -> &Self {
+        self.shared_pipeline().apply(&mut self.tokens);
 
-        self.tokens.iter_mut().for_each(|token|  {
-            let stem = Cow::Owned(stemmer.stem(token).to_string());
+        self
+    }
 
-            if stem != *token  {
-                *token = stem;
-            }
-        });
+    #[cfg(feature = "stem")]
+    fn stem(&mut self, lang: Algorithm) -> &Self {
+        Stem::new(lang).apply(&mut self.tokens);
 
         self
     }
-    
 }
 
 #[derive(Debug, Clone)]
 /// A struct dedicated to title cleaning
 /// 
-/// Cleaning process is made in this specific order :
-/// * subtitles are removed by spliting the title at it's first strong punctuation mark (`.`, `:`, `?`, `!`)
+/// Cleaning process is made by the [title pipeline](struct.Pipeline.html#method.title), in this specific order :
+/// * part designations (`Tome 2`, `vol. III`, `Bd. 4`) are taken apart from the title (see [numbering](#method.numbering))
+/// * subtitles are removed by spliting the title at it's first strong punctuation mark (`.`, `:`, `?`, `!`), subtitles can be kept apart (see [keep_subtitle](#method.keep_subtitle))
 /// * tokens between `(`, `)` and between `[`, `]` are removed
 /// * tokens smaller than three chars are removed 
 /// * HTML entities are decoded (html features)
 /// * tokens are transformed to lowercase
 /// * tokens are unidecoded (accentued chars are replaced by their ASCII equivalent)
 /// * non ASCII char are removed
-/// * punctuation and digit are removed
+/// * punctuation and digit are removed, digits can be kept (see [numbers](#method.numbers))
 /// * tokens are trimed (extra white space at the begining and end of each token are removed)
 /// * empty tokens are removed
 /// 
 /// Additionally token can be stemmed, howerver stemming implies huge performance downside. The stem feature must be enabled.
 pub struct TitleCleaner<'a> {
     tokens: Vec<Cow<'a, str>>,
-    token_min_lenght: usize
+    subtitle: Vec<Cow<'a, str>>,
+    numbering: Vec<Numbering>,
+    keep_subtitle: bool,
+    token_min_lenght: usize,
+    stopwords: Option<RemoveStopwords>,
+    roman_numerals: Option<RomanNumerals>,
+    numbers: NumericPolicy
 }
 
 /// ```
@@ -209,12 +328,37 @@ impl <'a>TitleCleaner<'a> {
     /// # }
     /// ```
     pub fn new<R: AsRef<str>>(input: &'a [R]) -> Self {
-        let mut tokens : Vec<Cow<'a, str>> = Vec::with_capacity(input.len());
-        tokens = input.into_iter().map(|token|Cow::Borrowed(token.as_ref())).collect();
+        TitleCleaner::with_tokens(input.iter().map(|token|Cow::Borrowed(token.as_ref())).collect())
+    }
+
+    /// Create a new TitleCleaner from a raw string
+    /// 
+    /// The input is split into tokens by the [Tokenizer](struct.Tokenizer.html), tokens are borrowed from the input.
+    /// 
+    /// ```
+    /// # use b_cleaner::{TitleCleaner, Clean};
+    /// # fn main() {   
+    /// let mut title_cleaner = TitleCleaner::from_str("Lorem ipsum dolor: sit amet");
+    /// title_cleaner.clean();
+    /// 
+    /// assert_eq!(title_cleaner.tokens(), &vec!["lorem", "ipsum", "dolor"]);
+    /// # }
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(input: &'a str) -> Self {
+        TitleCleaner::with_tokens(Tokenizer::new(input).map(Cow::Borrowed).collect())
+    }
 
+    pub(crate) fn with_tokens(tokens: Vec<Cow<'a, str>>) -> Self {
         TitleCleaner {
-            tokens: tokens,
-            token_min_lenght: 3
+            tokens,
+            subtitle: Vec::new(),
+            numbering: Vec::new(),
+            keep_subtitle: false,
+            token_min_lenght: 3,
+            stopwords: None,
+            roman_numerals: None,
+            numbers: NumericPolicy::Drop
         }
     }
 
@@ -234,6 +378,53 @@ impl <'a>TitleCleaner<'a> {
         &self.tokens
     }
 
+    /// Get the subtitle (other title information) out of the TitleCleaner
+    /// 
+    /// The subtitle is empty unless [keep_subtitle](#method.keep_subtitle) is enabled.
+    pub fn subtitle(&self) -> &Vec<Cow<'a, str>> {
+        &self.subtitle
+    }
+
+    /// Get the part designations taken out of the title, empty before cleaning
+    /// 
+    /// Designations are read before the title is split at its first strong punctuation mark, so different volumes of a title don't share the same tokens.
+    /// 
+    /// ```
+    /// # use b_cleaner::{TitleCleaner, Clean, Designation, Numbering};
+    /// # fn main() {   
+    /// let mut title_cleaner = TitleCleaner::from_str("Histoire de France. Tome 2");
+    /// 
+    /// title_cleaner.clean();
+    /// 
+    /// assert_eq!(title_cleaner.tokens(), &vec!["histoire", "france"]);
+    /// assert_eq!(title_cleaner.numbering(), &vec![Numbering::new(Designation::Volume, 2)]);
+    /// # }
+    /// ```
+    pub fn numbering(&self) -> &Vec<Numbering> {
+        &self.numbering
+    }
+
+    /// Keep the subtitle apart instead of discarding it
+    /// 
+    /// The tokens following the first strong punctuation mark are cleaned separately from the title proper.
+    /// 
+    /// ```
+    /// # use b_cleaner::{TitleCleaner, Clean};
+    /// # fn main() {   
+    /// let mut title_cleaner = TitleCleaner::from_str("Lorem ipsum: dolor sit amet. Consectetur");
+    /// title_cleaner.keep_subtitle(true);
+    /// 
+    /// title_cleaner.clean();
+    /// 
+    /// assert_eq!(title_cleaner.tokens(), &vec!["lorem", "ipsum"]);
+    /// assert_eq!(title_cleaner.subtitle(), &vec!["dolor", "amet", "consectetur"]);
+    /// # }
+    /// ```
+    pub fn keep_subtitle(&mut self, keep: bool) -> &Self {
+        self.keep_subtitle = keep;
+        self
+    }
+
     /// Set the token min length treshold (inclusive). Tokens under this treshold will be filtered out
     /// 
     /// ```
@@ -252,47 +443,143 @@ impl <'a>TitleCleaner<'a> {
         self.token_min_lenght = thresold;
         self
     }
-}
 
-impl <'a>Clean for TitleCleaner<'a> {
-    fn clean(&mut self) -> &Self {
-        tokens_split_at_strong_punctuation(&mut self.tokens);
-        remove_tokens_between_delimiters(&mut self.tokens, ("(", ")"));
-        remove_tokens_between_delimiters(&mut self.tokens, ("[", "]"));
+    /// Remove the stopwords of some languages, see [RemoveStopwords](struct.RemoveStopwords.html)
+    /// 
+    /// ```
+    /// # use b_cleaner::{TitleCleaner, Clean, Language};
+    /// # fn main() {   
+    /// let mut title_cleaner = TitleCleaner::from_str("Voyage dans la lune");
+    /// title_cleaner.stopwords(&[Language::English, Language::French]);
+    /// 
+    /// title_cleaner.clean();
+    /// 
+    /// assert_eq!(title_cleaner.tokens(), &vec!["voyage", "lune"]);
+    /// # }
+    /// ```
+    pub fn stopwords(&mut self, languages: &[Language]) -> &Self {
+        self.stopwords = Some(RemoveStopwords::new(languages));
+        self
+    }
+
+    /// Add user supplied stopwords
+    pub fn add_stopwords<S: AsRef<str>>(&mut self, words: &[S]) -> &Self {
+        self.stopwords.get_or_insert_with(RemoveStopwords::default).add_words(words);
+        self
+    }
+
+    /// Convert or remove Roman numerals, see [RomanNumerals](enum.RomanNumerals.html). Roman numerals are kept as words by default
+    /// 
+    /// Converted numerals are kept whatever the [numbers](#method.numbers) policy, digits are kept standalone instead of dropped.
+    /// 
+    /// ```
+    /// # use b_cleaner::{TitleCleaner, Clean, RomanNumerals};
+    /// # fn main() {   
+    /// let mut title_cleaner = TitleCleaner::from_str("Histoire du XIXe siècle");
+    /// title_cleaner.roman_numerals(RomanNumerals::Remove);
+    /// 
+    /// title_cleaner.clean();
+    /// 
+    /// assert_eq!(title_cleaner.tokens(), &vec!["histoire", "siecle"]);
+    /// # }
+    /// ```
+    pub fn roman_numerals(&mut self, roman_numerals: RomanNumerals) -> &Self {
+        self.roman_numerals = Some(roman_numerals);
+        self
+    }
+
+    /// Set what to do with numbers, see [NumericPolicy](enum.NumericPolicy.html). Digits are removed by default
+    /// 
+    /// Numbers are kept whatever their length unless they are dropped.
+    /// 
+    /// ```
+    /// # use b_cleaner::{TitleCleaner, Clean, NumericPolicy};
+    /// # fn main() {   
+    /// let mut title_cleaner = TitleCleaner::from_str("2001: a space odyssey");
+    /// title_cleaner.numbers(NumericPolicy::KeepStandalone);
+    /// 
+    /// title_cleaner.clean();
+    /// 
+    /// assert_eq!(title_cleaner.tokens(), &vec!["2001"]);
+    /// # }
+    /// ```
+    pub fn numbers(&mut self, policy: NumericPolicy) -> &Self {
+        self.numbers = policy;
+        self
+    }
 
+    /// Get the pipeline used to clean tokens, Roman numerals are handled first and stopwords are removed after the normalization steps, elided stopwords (`l'`, `dell'`) being split off before the punctuation is removed
+    pub fn pipeline(&self) -> Pipeline {
+        let mut pipeline = Pipeline::title(self.token_min_lenght);
+        pipeline.set_numeric_policy(self.numbers.with_roman_numerals(self.roman_numerals), self.token_min_lenght);
 
-        let token_min_lenght = self.token_min_lenght;
+        if let Some(roman_numerals) = self.roman_numerals {
+            pipeline.insert_step(0, roman_numerals);
+        }
 
-        self.tokens.retain(|token| !(token.len() <= token_min_lenght));
+        if let Some(stopwords) = &self.stopwords {
+            pipeline.add_stopwords(stopwords);
+        }
 
-        self.tokens.iter_mut().for_each(|mut token| {
-            #[cfg(feature = "html")]
-            decode_token_html_entities(&mut token);
+        pipeline
+    }
+
+    /// The pipeline used while cleaning, shared between the cleaners unless stopwords are removed
+    pub(crate) fn shared_pipeline(&self) -> Rc<Pipeline> {
+        match self.stopwords {
+            Some(_) => Rc::new(self.pipeline()),
+            None => Pipeline::preset(Preset::Title(self.token_min_lenght, self.numbers, self.roman_numerals), || self.pipeline())
+        }
+    }
+
+    /// Clean the tokens with a custom [Pipeline](struct.Pipeline.html) instead of the preset one
+    /// 
+    /// ```
+    /// # use b_cleaner::{TitleCleaner, Pipeline};
+    /// # fn main() {   
+    /// let mut pipeline = Pipeline::title(3);
+    /// pipeline.remove_step("lowercase");
+    /// 
+    /// let mut title_cleaner = TitleCleaner::from_str("Lorem ipsum: dolor");
+    /// title_cleaner.clean_with(&pipeline);
+    /// 
+    /// assert_eq!(title_cleaner.tokens(), &vec!["Lorem", "ipsum"]);
+    /// # }
+    /// ```
+    pub fn clean_with(&mut self, pipeline: &Pipeline) -> &Self {
+        // The numbering and the subtitle are only split off once, they are no longer part of the tokens when cleaning again
+        if self.numbering.is_empty() {
+            let (tokens, numbering) = split_off_numbering(std::mem::take(&mut self.tokens));
+            self.tokens = tokens;
+            self.numbering = numbering;
+        }
 
-            token_to_lowercase(&mut token);
-            unidecode_token(&mut token);
-            remove_token_non_ascii_chars(&mut token);
-            remove_token_digit_and_punctuation(&mut token);
-            token_trim(&mut token);
-        });
+        if self.keep_subtitle {
+            if self.subtitle.is_empty() {
+                self.subtitle = tokens_split_off_subtitle(&mut self.tokens);
+            }
 
-        self.tokens.retain(|token| !token.is_empty());
-        self.tokens.shrink_to_fit();
+            pipeline.apply_skipping(&mut self.subtitle, "split_at_strong_punctuation");
+        }
 
+        pipeline.apply(&mut self.tokens);
         self
     }
+}
+
+impl <'a>Clean for TitleCleaner<'a> {
+    fn clean(&mut self) -> &Self {
+        let pipeline = self.shared_pipeline();
+
+        self.clean_with(&pipeline)
+    }
 
     #[cfg(feature = "stem")]
     fn stem(&mut self, lang: Algorithm) -> &Self {
-        let stemmer = Stemmer::create(lang);
+        let stem = Stem::new(lang);
 
-        self.tokens.iter_mut().for_each(|token|  {
-            let stem = Cow::Owned(stemmer.stem(token).to_string());
-
-            if stem != *token  {
-                *token = stem;
-            }
-        });
+        stem.apply(&mut self.tokens);
+        stem.apply(&mut self.subtitle);
 
         self
     }
@@ -301,10 +588,13 @@ impl <'a>Clean for TitleCleaner<'a> {
 #[derive(Debug, Clone)]
 /// A struct dedicated to cleaning author
 /// 
-/// Cleaning process is made in this specific order :
+/// Cleaning process is made by the [author pipeline](struct.Pipeline.html#method.author), in this specific order :
+/// * role terms (`ed.`, `trad.`, `[et al.]`) are removed (see [roles](#method.roles)), unless [keep_roles](#method.keep_roles) is enabled
+/// * honorifics and suffix are removed (see [honorifics](#method.honorifics) and [suffix](#method.suffix)), unless [keep_honorifics](#method.keep_honorifics) is enabled
+/// * life dates are extracted (see [dates](#method.dates))
+/// * tokens between `(`, `)` and between `[`, `]` are removed
 /// * HTML entities are decoded (html features)
 /// * tokens are transformed to lowercase
-/// * tokens between `(`, `)` and between `[`, `]` are removed
 /// * tokens are unidecoded (accentued chars are replaced by their ASCII equivalent)
 /// * non ASCII char are removed
 /// * punctuation and digit are removed
@@ -313,7 +603,13 @@ impl <'a>Clean for TitleCleaner<'a> {
 /// 
 /// Additionally token can be stemmed, howerver stemming implies huge performance downside. The stem feature must be enabled.
 pub struct AuthorCleaner<'a> {
-    tokens: Vec<Cow<'a, str>>
+    tokens: Vec<Cow<'a, str>>,
+    dates: Option<LifeDates>,
+    roles: Vec<Role>,
+    honorifics: Vec<Cow<'a, str>>,
+    suffix: Option<Cow<'a, str>>,
+    canonical_order: bool,
+    name_options: NameOptions
 }
 
 /// ```
@@ -338,11 +634,36 @@ impl <'a>AuthorCleaner<'a> {
     /// # }
     /// ```
     pub fn new<R: AsRef<str>>(input: &'a [R]) -> Self {
-        let mut tokens : Vec<Cow<'a, str>> = Vec::with_capacity(input.len());
-        tokens = input.into_iter().map(|token|Cow::Borrowed(token.as_ref())).collect();
+        AuthorCleaner::with_tokens(input.iter().map(|token| Cow::Borrowed(token.as_ref())))
+    }
+
+    /// Create a new AuthorCleaner from a raw string
+    /// 
+    /// The input is split into tokens by the [Tokenizer](struct.Tokenizer.html), tokens are borrowed from the input.
+    /// 
+    /// ```
+    /// # use b_cleaner::{AuthorCleaner, Clean};
+    /// # fn main() {   
+    /// let mut author_cleaner = AuthorCleaner::from_str("John W. Doe (1950-2020)");
+    /// author_cleaner.clean();
+    /// 
+    /// assert_eq!(author_cleaner.tokens(), &vec!["john", "w", "doe"]);
+    /// # }
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(input: &'a str) -> Self {
+        AuthorCleaner::with_tokens(Tokenizer::new(input).map(Cow::Borrowed))
+    }
 
+    pub(crate) fn with_tokens<I: Iterator<Item = Cow<'a, str>>>(tokens: I) -> Self {
         AuthorCleaner {
-            tokens: tokens
+            tokens: tokens.collect(),
+            dates: None,
+            roles: Vec::new(),
+            honorifics: Vec::new(),
+            suffix: None,
+            canonical_order: false,
+            name_options: NameOptions::default()
         }
     }
 
@@ -360,48 +681,275 @@ impl <'a>AuthorCleaner<'a> {
     pub fn tokens(&self) -> &Vec<Cow<'a, str>> {
         &self.tokens
     }
-}
 
-impl <'a>Clean for AuthorCleaner<'a> {
-    fn clean(&mut self) -> &Self {
-        remove_tokens_between_delimiters(&mut self.tokens, ("(", ")"));
-        remove_tokens_between_delimiters(&mut self.tokens, ("[", "]"));
-
-        self.tokens.iter_mut().for_each(|mut token| {
-            #[cfg(feature = "html")]
-            decode_token_html_entities(&mut token);
-            
-            token_to_lowercase(&mut token);
-            
-            remove_token_digit_and_punctuation(&mut token);
-            unidecode_token(&mut token);
-            remove_token_non_ascii_chars(&mut token);
-            token_trim(&mut token);
-        });
-
-        self.tokens.retain(|token| !token.is_empty());
-        self.tokens.shrink_to_fit();
+    /// Parse the tokens into a structured [AuthorName](struct.AuthorName.html)
+    /// 
+    /// The name must be parsed before cleaning since cleaning removes the punctuation used to split the name.
+    /// 
+    /// ```
+    /// # use b_cleaner::AuthorCleaner;
+    /// # fn main() {   
+    /// let author_cleaner = AuthorCleaner::from_str("Doe, John W. (1950-2020)");
+    /// 
+    /// assert_eq!(author_cleaner.name().key(), "doe john w");
+    /// # }
+    /// ```
+    pub fn name(&self) -> AuthorName<'a> {
+        AuthorName::parse(self.tokens.clone(), &self.name_options)
+    }
 
+    /// Output names in canonical order (surname first) while cleaning, the order of names without comma is read according to the [AmbiguousOrder](enum.AmbiguousOrder.html) policy
+    /// 
+    /// ```
+    /// # use b_cleaner::{AuthorCleaner, AmbiguousOrder, Clean};
+    /// # fn main() {   
+    /// let mut inverted = AuthorCleaner::from_str("Doe, John W. (1950-2020)");
+    /// inverted.canonical_order(AmbiguousOrder::ForenamesFirst);
+    /// inverted.clean();
+    /// 
+    /// let mut direct = AuthorCleaner::from_str("John W. Doe");
+    /// direct.canonical_order(AmbiguousOrder::ForenamesFirst);
+    /// direct.clean();
+    /// 
+    /// assert_eq!(inverted.tokens(), &vec!["doe", "john", "w"]);
+    /// assert_eq!(inverted.tokens(), direct.tokens());
+    /// # }
+    /// ```
+    pub fn canonical_order(&mut self, order: AmbiguousOrder) -> &Self {
+        self.canonical_order = true;
+        self.name_options.order = order;
         self
     }
 
-    #[cfg(feature = "stem")]
-     fn stem(&mut self, lang: Algorithm) -> &Self {
-        let stemmer = Stemmer::create(lang);
+    /// Recognize the particles of some languages, see [Particles](struct.Particles.html). Particles of all the supported languages are recognized by default
+    /// 
+    /// ```
+    /// # use b_cleaner::{AuthorCleaner, Language};
+    /// # fn main() {   
+    /// let mut author_cleaner = AuthorCleaner::from_str("Van Beethoven, Ludwig");
+    /// author_cleaner.particles(&[Language::German, Language::Dutch]);
+    /// 
+    /// assert_eq!(author_cleaner.name().particles(), &vec!["van"]);
+    /// # }
+    /// ```
+    pub fn particles(&mut self, languages: &[Language]) -> &Self {
+        self.name_options.particles = Particles::new(languages);
+        self
+    }
+
+    /// Add user supplied particles
+    pub fn add_particles<S: AsRef<str>>(&mut self, particles: &[S]) -> &Self {
+        self.name_options.particles.add_particles(particles);
+        self
+    }
+
+    /// Set what to do with particles, they are attached to the surname by default
+    /// 
+    /// ```
+    /// # use b_cleaner::{AuthorCleaner, AmbiguousOrder, ParticlePolicy, Clean};
+    /// # fn main() {   
+    /// let mut inverted = AuthorCleaner::from_str("Beethoven, Ludwig van");
+    /// inverted.particle_policy(ParticlePolicy::Drop);
+    /// inverted.clean();
+    /// 
+    /// let mut direct = AuthorCleaner::from_str("Ludwig van Beethoven");
+    /// direct.canonical_order(AmbiguousOrder::ForenamesFirst);
+    /// direct.particle_policy(ParticlePolicy::Drop);
+    /// direct.clean();
+    /// 
+    /// assert_eq!(inverted.tokens(), &vec!["beethoven", "ludwig"]);
+    /// assert_eq!(direct.tokens(), &vec!["beethoven", "ludwig"]);
+    /// # }
+    /// ```
+    pub fn particle_policy(&mut self, policy: ParticlePolicy) -> &Self {
+        self.name_options.particle_policy = policy;
+        self
+    }
+
+    /// Keep the role terms as name tokens instead of removing them, so that a custom pipeline gets the whole name
+    /// 
+    /// ```
+    /// # use b_cleaner::{AuthorCleaner, Clean};
+    /// # fn main() {   
+    /// let mut author_cleaner = AuthorCleaner::from_str("Dupont, Jean, dir.");
+    /// author_cleaner.keep_roles(true);
+    /// author_cleaner.clean();
+    /// 
+    /// assert_eq!(author_cleaner.tokens(), &vec!["dupont", "jean", "dir"]);
+    /// assert!(author_cleaner.roles().is_empty());
+    /// # }
+    /// ```
+    pub fn keep_roles(&mut self, keep: bool) -> &Self {
+        self.name_options.keep_roles = keep;
+        self
+    }
+
+    /// Keep the honorifics and the suffix as name tokens instead of removing them
+    /// 
+    /// ```
+    /// # use b_cleaner::{AuthorCleaner, Clean};
+    /// # fn main() {   
+    /// let mut author_cleaner = AuthorCleaner::from_str("Dr. John Doe Jr.");
+    /// author_cleaner.keep_honorifics(true);
+    /// author_cleaner.clean();
+    /// 
+    /// assert_eq!(author_cleaner.tokens(), &vec!["dr", "john", "doe", "jr"]);
+    /// assert!(author_cleaner.honorifics().is_empty());
+    /// # }
+    /// ```
+    pub fn keep_honorifics(&mut self, keep: bool) -> &Self {
+        self.name_options.keep_honorifics = keep;
+        self
+    }
 
-        self.tokens.iter_mut().for_each(|token|  {
-            let stem = Cow::Owned(stemmer.stem(token).to_string());
+    /// Get the life dates extracted while cleaning
+    /// 
+    /// ```
+    /// # use b_cleaner::{AuthorCleaner, Clean, Year};
+    /// # fn main() {   
+    /// let mut author_cleaner = AuthorCleaner::from_str("John W. Doe (ca. 1950-2020)");
+    /// author_cleaner.clean();
+    /// 
+    /// let dates = author_cleaner.dates().unwrap();
+    /// 
+    /// assert_eq!(author_cleaner.tokens(), &vec!["john", "w", "doe"]);
+    /// assert_eq!(dates.birth(), Some(Year::new(1950)));
+    /// assert_eq!(dates.death(), Some(Year::new(2020)));
+    /// assert!(dates.approximate());
+    /// # }
+    /// ```
+    pub fn dates(&self) -> Option<&LifeDates> {
+        self.dates.as_ref()
+    }
+
+    /// Get the roles removed while cleaning, see [Role](enum.Role.html)
+    /// 
+    /// ```
+    /// # use b_cleaner::{AuthorCleaner, Clean, Role};
+    /// # fn main() {   
+    /// let mut author_cleaner = AuthorCleaner::from_str("Dupont, Jean (dir.)");
+    /// author_cleaner.clean();
+    /// 
+    /// assert_eq!(author_cleaner.tokens(), &vec!["dupont", "jean"]);
+    /// assert_eq!(author_cleaner.roles(), &vec![Role::Editor]);
+    /// # }
+    /// ```
+    pub fn roles(&self) -> &Vec<Role> {
+        &self.roles
+    }
+
+    /// Get the honorifics (`Dr.`, `Prof.`, `Mme`) removed while cleaning, they are cleaned as the name
+    /// 
+    /// ```
+    /// # use b_cleaner::{AuthorCleaner, Clean};
+    /// # fn main() {   
+    /// let mut author_cleaner = AuthorCleaner::from_str("Prof. Dr. John Doe III");
+    /// author_cleaner.clean();
+    /// 
+    /// assert_eq!(author_cleaner.tokens(), &vec!["john", "doe"]);
+    /// assert_eq!(author_cleaner.honorifics(), &vec!["prof", "dr"]);
+    /// assert_eq!(author_cleaner.suffix().unwrap(), "iii");
+    /// # }
+    /// ```
+    pub fn honorifics(&self) -> &Vec<Cow<'a, str>> {
+        &self.honorifics
+    }
+
+    /// Get the suffix (`Jr.`, `III`) removed while cleaning, it's cleaned as the name
+    pub fn suffix(&self) -> Option<&Cow<'a, str>> {
+        self.suffix.as_ref()
+    }
+
+    /// Clean the tokens with a custom [Pipeline](struct.Pipeline.html) instead of the preset one
+    /// 
+    /// ```
+    /// # use b_cleaner::{AuthorCleaner, Pipeline};
+    /// # fn main() {   
+    /// let mut pipeline = Pipeline::author();
+    /// pipeline.remove_step("lowercase");
+    /// 
+    /// let mut author_cleaner = AuthorCleaner::from_str("John Doe (1950-2020)");
+    /// author_cleaner.clean_with(&pipeline);
+    /// 
+    /// assert_eq!(author_cleaner.tokens(), &vec!["John", "Doe"]);
+    /// # }
+    /// ```
+    pub fn clean_with(&mut self, pipeline: &Pipeline) -> &Self {
+        // Roles, honorifics and the suffix are only split off once, they are no longer part of the tokens when cleaning again
+        if !self.name_options.keep_roles && self.roles.is_empty() {
+            let (tokens, roles) = split_off_roles(std::mem::take(&mut self.tokens));
+            self.tokens = tokens;
+            self.roles = roles;
+        }
+
+        if !self.name_options.keep_honorifics && self.honorifics.is_empty() && self.suffix.is_none() {
+            let (tokens, affixes) = split_off_honorifics(std::mem::take(&mut self.tokens));
+            self.tokens = tokens;
+            self.honorifics = affixes.honorifics;
+            self.suffix = affixes.suffix;
+        }
+
+        if self.tokens.iter().any(|token| token.chars().any(|c| c.is_ascii_digit())) {
+            let (tokens, dates) = split_off_dates(std::mem::take(&mut self.tokens));
+            self.tokens = tokens;
+            self.dates = LifeDates::from_tokens(&dates);
+        }
+
+        if self.canonical_order {
+            let name = AuthorName::parse_raw(self.tokens.clone(), &self.name_options);
 
-            if stem != *token  {
-                *token = stem;
+            if self.name_options.order != AmbiguousOrder::Keep || name.form() == NameForm::Inverted {
+                self.tokens = name.into_canonical_tokens();
+            } else {
+                self.drop_particles();
             }
-        });
+        } else {
+            self.drop_particles();
+        }
+
+        pipeline.apply(&mut self.tokens);
+        pipeline.apply(&mut self.honorifics);
+
+        if let Some(suffix) = self.suffix.take() {
+            let mut suffix = vec![suffix];
+            pipeline.apply(&mut suffix);
+            self.suffix = suffix.pop();
+        }
+
+        self
+    }
+
+    pub(crate) fn into_tokens(self) -> Vec<Cow<'a, str>> {
+        self.tokens
+    }
+
+    /// Removes the known particles when the particles policy is to drop them, a particle which is the whole surname (`Das, Veena`) is kept
+    fn drop_particles(&mut self) {
+        if self.name_options.particle_policy == ParticlePolicy::Drop {
+            self.tokens = self.name_options.particles.split_elisions(std::mem::take(&mut self.tokens));
+
+            let particles = self.name_options.particles.mark_removable(&self.tokens);
+            let mut particles = particles.into_iter();
+
+            self.tokens.retain(|_| !particles.next().unwrap_or(false));
+        }
+    }
+}
+
+impl <'a>Clean for AuthorCleaner<'a> {
+    fn clean(&mut self) -> &Self {
+        self.clean_with(&Pipeline::preset(Preset::Author, Pipeline::author))
+    }
+
+    #[cfg(feature = "stem")]
+    fn stem(&mut self, lang: Algorithm) -> &Self {
+        Stem::new(lang).apply(&mut self.tokens);
 
         self
     }
-} 
+}
 
-fn token_to_lowercase<'a>(token: &mut Cow<'a, str>) {
+pub(crate) fn token_to_lowercase<'a>(token: &mut Cow<'a, str>) {
     if token.chars().filter(|c| c.is_ascii_alphabetic()).any(|char| !char.is_ascii_lowercase()) {
         match token {
             Cow::Borrowed(_) => *token = Cow::Owned(token.to_lowercase()),
@@ -410,7 +958,7 @@ fn token_to_lowercase<'a>(token: &mut Cow<'a, str>) {
     }
 }
 
-fn token_trim<'a>(token: &mut Cow<'a, str>) {
+pub(crate) fn token_trim<'a>(token: &mut Cow<'a, str>) {
     let chars : Vec<char> = token.chars().collect();
 
     match (chars.last(),  chars.first()) {
@@ -429,27 +977,35 @@ fn token_trim<'a>(token: &mut Cow<'a, str>) {
 }
 
 /// Removes the subtitle of a list of tokens
-fn tokens_split_at_strong_punctuation<'a>(tokens: &mut Vec<Cow<'a, str>>) {
+pub(crate) fn tokens_split_at_strong_punctuation<'a>(tokens: &mut Vec<Cow<'a, str>>) {
+    tokens_split_off_subtitle(tokens);
+}
+
+/// Splits a list of tokens at its first strong punctuation mark and returns the tokens following the punctuation mark
+pub(crate) fn tokens_split_off_subtitle<'a>(tokens: &mut Vec<Cow<'a, str>>) -> Vec<Cow<'a, str>> {
     let hard_punct = tokens.iter().position(|e| e.ends_with('.') || e.ends_with(':') || e.ends_with('?') || e.ends_with('!'));
         
     if let Some(hard_punct) = hard_punct {
-        let (temp_tokens,_) = tokens.split_at(hard_punct);
-
-        *tokens = temp_tokens.to_vec();
+        let subtitle = tokens.split_off(hard_punct + 1);
+        tokens.truncate(hard_punct);
 
         tokens.shrink_to_fit();
+
+        return subtitle;
     }
+
+    Vec::new()
 }
 
 /// Replace accented chars in a token by their unidecoded counterpart
 pub fn unidecode_token<'a>(token: &mut Cow<'a, str>) {
-    if token.chars().any(|char| !char.is_ascii()) {
-        *token = unidecode(&token).into();
+    if !token.is_ascii() {
+        *token = unidecode(token).into();
     }
 }
 
 /// Removes all digits of a token
-fn remove_token_digit<'a>(token: &mut Cow<'a, str>) {
+pub(crate) fn remove_token_digit<'a>(token: &mut Cow<'a, str>) {
     if token.chars().any(|char| char.is_ascii_digit()) {
         *token = token.chars().filter(|char| !char.is_ascii_digit()).collect();
     }
@@ -464,7 +1020,7 @@ pub fn remove_token_punctuation<'a>(token: &mut Cow<'a, str>) {
 
 /// Removes digit and punctuation of a token except `-` which can be used to join words
 pub fn remove_token_digit_and_punctuation<'a>(token:  &mut Cow<'a, str>) {
-    if token.chars().any(|char| (char.is_ascii_digit() || char.is_ascii_punctuation())) {
+    if token.chars().any(|char| char.is_ascii_digit() || char.is_ascii_punctuation()) {
         *token = token.chars().enumerate().filter(|(index, c)| !c.is_ascii_punctuation() && !c.is_ascii_digit() || (*c == '-' && *index > 0 && *index < token.len())).map(|(_, c)| c).collect();
     }
 }
@@ -494,7 +1050,7 @@ pub fn remove_token_non_ascii_chars<'a>(token: &mut Cow<'a, str>) {
 /// assert_eq!(tokens, vec!["lorem", "sit", "amet"]);
 /// # }
 /// ```
-pub fn remove_tokens_between_delimiters<'a>(tokens: &mut Vec<Cow<'a, str>>, delimiters: (&'a str, &'a str)) {
+pub fn remove_tokens_between_delimiters<'a>(tokens: &mut Vec<Cow<'a, str>>, delimiters: (&str, &str)) {
     while let Some(start) = tokens.iter().position(|token| token.starts_with(delimiters.0)) {
         if let Some(end) = tokens[start ..].iter().position(|token| token.ends_with(delimiters.1)) {
             tokens.drain(start.. end + start + 1);
@@ -508,22 +1064,22 @@ pub fn remove_tokens_between_delimiters<'a>(tokens: &mut Vec<Cow<'a, str>>, deli
 /// Replace HTML encoded entities with their decoded counterpart
 pub fn decode_token_html_entities<'a>(token: &mut Cow<'a, str>) {
     if token.starts_with('&') && token.ends_with(';') {
-        match htmlescape::decode_html(&token) {
-            Ok(escaped) => {
-                if &escaped != token {
-                    *token = Cow::Owned(escaped);          
-                }
-            },
-            Err(_) => ()
-        };
+        if let Ok(escaped) = htmlescape::decode_html(token) {
+            if &escaped != token {
+                *token = Cow::Owned(escaped);
+            }
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::numbering::Designation;
+    use crate::author::Year;
 
     #[test]
+    #[allow(clippy::suspicious_to_owned)]
     fn test_remove_digit_and_punctuation() {
         let mut tokens : Cow<str>= Cow::Borrowed("W.#");        
 
@@ -535,6 +1091,7 @@ mod tests {
 
     #[test]
 
+    #[allow(clippy::redundant_closure)]
     fn test_remove_tokens_between_delimiters() {
         let mut input = vec!["abcdef", "(ezrà)", "sdfq", "(sss)"].into_iter().map(|e| Cow::Borrowed(e)).collect();
         
@@ -543,6 +1100,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::redundant_closure)]
     fn test_author_cleaner() {
         let tokens = vec!["John", "W.", "Doe", "(1950-2018)"];
         let mut author = AuthorCleaner::new(&tokens);
@@ -552,6 +1110,221 @@ mod tests {
         assert_eq!(author.tokens(), &vec!["john", "w", "doe"].into_iter().map(|e| Cow::Borrowed(e)).collect::<Vec<Cow<str>>>());
     }
 
+    #[test]
+    fn test_author_cleaner_initials_as_role() {
+        let mut author = AuthorCleaner::from_str("Fanthorpe, U. A.");
+        author.clean();
+
+        assert_eq!(author.tokens(), &vec!["fanthorpe", "u", "a"]);
+        assert!(author.roles().is_empty());
+    }
+
+    #[test]
+    fn test_author_cleaner_names_read_as_roles() {
+        let mut author = AuthorCleaner::from_str("Nagy, Edit");
+        author.clean();
+
+        assert_eq!(author.tokens(), &vec!["nagy", "edit"]);
+        assert!(author.roles().is_empty());
+
+        let mut author = AuthorCleaner::from_str("Comp, Jean");
+        author.clean();
+
+        assert_eq!(author.tokens(), &vec!["comp", "jean"]);
+        assert!(author.roles().is_empty());
+    }
+
+    #[test]
+    fn test_author_cleaner_clean_twice() {
+        let mut author = AuthorCleaner::from_str("Dr. John Smith, Jr., ed.");
+        author.clean();
+        author.clean();
+
+        assert_eq!(author.tokens(), &vec!["john", "smith"]);
+        assert_eq!(author.roles(), &vec![Role::Editor]);
+        assert_eq!(author.honorifics(), &vec!["dr"]);
+        assert_eq!(author.suffix().unwrap(), "jr");
+    }
+
+    #[test]
+    fn test_author_cleaner_unbracketed_dates() {
+        let mut author = AuthorCleaner::from_str("King, Martin Luther, 1929-1968");
+        author.clean();
+
+        assert_eq!(author.tokens(), &vec!["king", "martin", "luther"]);
+        assert_eq!(author.dates().and_then(|dates| dates.death()), Some(Year::new(1968)));
+
+        let mut author = AuthorCleaner::from_str("John Doe 1950-2018");
+        author.clean();
+
+        assert_eq!(author.tokens(), &vec!["john", "doe"]);
+        let mut author = AuthorCleaner::from_str("Doe, John (1950-");
+        author.clean();
+
+        assert_eq!(author.tokens(), &vec!["doe", "john"]);
+        assert_eq!(author.dates().and_then(|dates| dates.birth()), Some(Year::new(1950)));
+    }
+
+    #[test]
+    fn test_author_cleaner_keep_roles_and_honorifics() {
+        let mut pipeline = Pipeline::author();
+        pipeline.remove_step("lowercase");
+
+        let mut author = AuthorCleaner::from_str("Dr. Dupont, Jean, trad.");
+        author.keep_roles(true);
+        author.keep_honorifics(true);
+        author.clean_with(&pipeline);
+
+        assert_eq!(author.tokens(), &vec!["Dr", "Dupont", "Jean", "trad"]);
+        assert!(author.roles().is_empty() && author.honorifics().is_empty());
+
+        let mut author = AuthorCleaner::from_str("Dupont, Jean, trad.");
+        author.keep_roles(true);
+        author.canonical_order(AmbiguousOrder::ForenamesFirst);
+        author.clean();
+
+        assert_eq!(author.tokens(), &vec!["dupont", "jean", "trad"]);
+    }
+
+    #[test]
+    fn test_author_cleaner_canonical_order() {
+        let mut author = AuthorCleaner::from_str("DOE John W. (1950-2020)");
+        author.canonical_order(AmbiguousOrder::Keep);
+        author.clean();
+
+        assert_eq!(author.tokens(), &vec!["doe", "john", "w"]);
+
+        let mut author = AuthorCleaner::from_str("John W. DOE");
+        author.canonical_order(AmbiguousOrder::Uppercase);
+        author.clean();
+
+        assert_eq!(author.tokens(), &vec!["doe", "john", "w"]);
+
+        let mut author = AuthorCleaner::from_str("Beethoven, Ludwig van");
+        author.canonical_order(AmbiguousOrder::Keep);
+        author.clean();
+
+        assert_eq!(author.tokens(), &vec!["van", "beethoven", "ludwig"]);
+    }
+
+    #[test]
+    fn test_author_cleaner_particle_surnames() {
+        for (input, expected) in [("Das, Veena", vec!["das", "veena"]), ("Veena Das", vec!["das", "veena"]), ("La Fontaine, Jean de", vec!["la", "fontaine", "jean"]), ("Jean de La Fontaine", vec!["la", "fontaine", "jean"])] {
+            let mut author = AuthorCleaner::from_str(input);
+            author.canonical_order(AmbiguousOrder::ForenamesFirst);
+            author.particle_policy(ParticlePolicy::Drop);
+            author.clean();
+
+            assert_eq!(author.tokens(), &expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_title_cleaner_keep_subtitle() {
+        let tokens = vec!["Lorem", "ipsum", "dolor", ":", "sit", "amet", "(consectetur)"];
+        let mut title = TitleCleaner::new(&tokens);
+        title.keep_subtitle(true);
+
+        title.clean();
+
+        assert_eq!(title.tokens(), &vec!["lorem", "ipsum", "dolor"]);
+        assert_eq!(title.subtitle(), &vec!["amet"]);
+
+        let mut title = TitleCleaner::new(&tokens);
+        title.clean();
+
+        assert!(title.subtitle().is_empty());
+    }
+
+    #[test]
+    fn test_elided_stopwords() {
+        let mut text = TextCleaner::from_str("l'histoire de la France d'aujourd'hui");
+        text.stopwords(&[Language::French]);
+        text.clean();
+
+        assert_eq!(text.tokens(), &vec!["histoire", "france", "aujourdhui"]);
+
+        let mut title = TitleCleaner::from_str("Storia dell’arte italiana");
+        title.stopwords(&[Language::Italian]);
+        title.clean();
+
+        assert_eq!(title.tokens(), &vec!["storia", "arte", "italiana"]);
+
+        let mut title = TitleCleaner::from_str("La forme de l'os");
+        title.stopwords(&[Language::French]);
+        title.clean();
+
+        assert_eq!(title.tokens(), &vec!["forme"]);
+    }
+
+    #[test]
+    fn test_title_cleaner_numbers() {
+        let key = |input: &str, policy: NumericPolicy| {
+            let mut title = TitleCleaner::from_str(input);
+            title.numbers(policy);
+            title.clean();
+
+            title.tokens().join(" ")
+        };
+
+        assert_eq!(key("Catch-22", NumericPolicy::Drop), "catch-");
+        assert_eq!(key("Catch-22", NumericPolicy::Keep), "catch-22");
+        assert_eq!(key("1984", NumericPolicy::Drop), "");
+        assert_eq!(key("1984", NumericPolicy::KeepStandalone), "1984");
+        assert_eq!(key("Apollo 13", NumericPolicy::Placeholder), "apollo #");
+        assert_ne!(key("Apollo 13", NumericPolicy::Keep), key("Apollo 11", NumericPolicy::Keep));
+    }
+
+    #[test]
+    fn test_roman_numerals_conversion() {
+        let key = |input: &str, policy: NumericPolicy| {
+            let mut title = TitleCleaner::from_str(input);
+            title.roman_numerals(RomanNumerals::Convert);
+            title.numbers(policy);
+            title.clean();
+
+            title.tokens().join(" ")
+        };
+
+        assert_eq!(key("XIXe siècle", NumericPolicy::Keep), "19 siecle");
+        assert_eq!(key("XIXe siècle", NumericPolicy::Drop), key("19 siècle", NumericPolicy::Keep));
+        assert_eq!(key("Louis XIV", NumericPolicy::Drop), "louis 14");
+        assert_eq!(key("Louis XIV", NumericPolicy::Placeholder), "louis #");
+
+        let mut text = TextCleaner::from_str("Le XXe siècle");
+        text.roman_numerals(RomanNumerals::Convert);
+        text.clean();
+
+        assert_eq!(text.tokens(), &vec!["20", "siecle"]);
+    }
+
+    #[test]
+    fn test_title_cleaner_numbering() {
+        let mut first = TitleCleaner::from_str("Œuvres complètes, vol. III");
+        let mut second = TitleCleaner::from_str("Œuvres complètes. Tome 4");
+        first.clean();
+        second.clean();
+
+        assert_eq!(first.tokens(), second.tokens());
+        assert_eq!(first.numbering(), &vec![Numbering::new(Designation::Volume, 3)]);
+        assert_eq!(second.numbering(), &vec![Numbering::new(Designation::Volume, 4)]);
+
+        let mut title = TitleCleaner::from_str("Band 4: Die Zeit der Könige");
+        title.clean();
+
+        assert_eq!(title.tokens(), &vec!["zeit", "konige"]);
+        assert_eq!(title.numbering(), &vec![Numbering::new(Designation::Volume, 4)]);
+
+        let mut title = TitleCleaner::from_str("Histoire de France. Tome 2 : Les Capétiens");
+        title.keep_subtitle(true);
+        title.clean();
+        title.clean();
+
+        assert_eq!(title.tokens(), &vec!["histoire", "france"]);
+        assert_eq!(title.subtitle(), &vec!["capetiens"]);
+        assert_eq!(title.numbering(), &vec![Numbering::new(Designation::Volume, 2)]);
+    }
+
     #[test]
     #[cfg(feature = "html")]
     fn test_decode_token_html_entities() {
@@ -0,0 +1,674 @@
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::author::{AmbiguousOrder, ParticlePolicy};
+use crate::cleaners::{AuthorCleaner, TextCleaner, TitleCleaner};
+use crate::pipeline::*;
+use crate::language::Language;
+use crate::stopwords::RemoveStopwords;
+use crate::tokenizer::Tokenizer;
+
+#[cfg(feature = "stem")]
+use rust_stemmers::Algorithm;
+
+/// Latest version of the profile format
+pub const CONFIG_VERSION: u32 = 1;
+
+/// Errors raised while loading a [CleanerConfig](struct.CleanerConfig.html)
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The profile file can't be read
+    Io(io::Error),
+    /// The TOML profile is invalid
+    Toml(toml::de::Error),
+    /// The JSON profile is invalid
+    Json(serde_json::Error),
+    /// The file extension is neither `.toml` nor `.json`
+    UnknownFormat(String),
+    /// The profile was written for a newer version of b-cleaner
+    UnsupportedVersion(u32),
+    /// The profile asks for stemming but the stem feature is disabled
+    StemmingDisabled(StemLanguage),
+    /// The option doesn't apply to the cleaner the profile is based on
+    InapplicableOption(&'static str, CleanerKind)
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(e) => write!(f, "unable to read profile: {}", e),
+            ConfigError::Toml(e) => write!(f, "invalid TOML profile: {}", e),
+            ConfigError::Json(e) => write!(f, "invalid JSON profile: {}", e),
+            ConfigError::UnknownFormat(path) => write!(f, "unknown profile format for {}, expected a .toml or .json file", path),
+            ConfigError::UnsupportedVersion(version) => write!(f, "unsupported profile version {}, latest supported version is {}", version, CONFIG_VERSION),
+            ConfigError::StemmingDisabled(lang) => write!(f, "unable to stem in {:?}, b-cleaner was built without the stem feature", lang),
+            ConfigError::InapplicableOption(option, cleaner) => write!(f, "option {} doesn't apply to the {:?} cleaner", option, cleaner)
+        }
+    }
+}
+
+impl Error for ConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ConfigError::Io(e) => Some(e),
+            ConfigError::Toml(e) => Some(e),
+            ConfigError::Json(e) => Some(e),
+            _ => None
+        }
+    }
+}
+
+impl From<io::Error> for ConfigError {
+    fn from(e: io::Error) -> Self {
+        ConfigError::Io(e)
+    }
+}
+
+impl From<toml::de::Error> for ConfigError {
+    fn from(e: toml::de::Error) -> Self {
+        ConfigError::Toml(e)
+    }
+}
+
+impl From<serde_json::Error> for ConfigError {
+    fn from(e: serde_json::Error) -> Self {
+        ConfigError::Json(e)
+    }
+}
+
+/// The kind of cleaner a profile is based on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CleanerKind {
+    /// See [TextCleaner](struct.TextCleaner.html)
+    #[default]
+    Text,
+    /// See [TitleCleaner](struct.TitleCleaner.html)
+    Title,
+    /// See [AuthorCleaner](struct.AuthorCleaner.html)
+    Author
+}
+
+/// Languages of the stemmers (`french`, `english`, etc.), stemming requires the stem feature
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StemLanguage {
+    /// Arabic
+    Arabic,
+    /// Danish
+    Danish,
+    /// Dutch
+    Dutch,
+    /// English
+    English,
+    /// Finnish
+    Finnish,
+    /// French
+    French,
+    /// German
+    German,
+    /// Greek
+    Greek,
+    /// Hungarian
+    Hungarian,
+    /// Italian
+    Italian,
+    /// Norwegian
+    Norwegian,
+    /// Portuguese
+    Portuguese,
+    /// Romanian
+    Romanian,
+    /// Russian
+    Russian,
+    /// Spanish
+    Spanish,
+    /// Swedish
+    Swedish,
+    /// Tamil
+    Tamil,
+    /// Turkish
+    Turkish
+}
+
+#[cfg(feature = "stem")]
+impl From<StemLanguage> for Algorithm {
+    fn from(lang: StemLanguage) -> Self {
+        match lang {
+            StemLanguage::Arabic => Algorithm::Arabic,
+            StemLanguage::Danish => Algorithm::Danish,
+            StemLanguage::Dutch => Algorithm::Dutch,
+            StemLanguage::English => Algorithm::English,
+            StemLanguage::Finnish => Algorithm::Finnish,
+            StemLanguage::French => Algorithm::French,
+            StemLanguage::German => Algorithm::German,
+            StemLanguage::Greek => Algorithm::Greek,
+            StemLanguage::Hungarian => Algorithm::Hungarian,
+            StemLanguage::Italian => Algorithm::Italian,
+            StemLanguage::Norwegian => Algorithm::Norwegian,
+            StemLanguage::Portuguese => Algorithm::Portuguese,
+            StemLanguage::Romanian => Algorithm::Romanian,
+            StemLanguage::Russian => Algorithm::Russian,
+            StemLanguage::Spanish => Algorithm::Spanish,
+            StemLanguage::Swedish => Algorithm::Swedish,
+            StemLanguage::Tamil => Algorithm::Tamil,
+            StemLanguage::Turkish => Algorithm::Turkish
+        }
+    }
+}
+
+/// A declarative cleaner profile (config feature)
+///
+/// Profiles can be loaded from TOML or JSON and turned into a [Pipeline](struct.Pipeline.html) and a configured cleaner, see [Profile](struct.Profile.html). Missing fields take the default value of the cleaner the profile is based on, options which don't apply to that cleaner are rejected.
+///
+/// ```toml
+/// version = 1
+/// cleaner = "title"
+/// token_min_lenght = 2
+/// split_subtitle = false
+/// delimiters = [["(", ")"]]
+/// stopwords = ["fr", "english"]
+/// extra_stopwords = ["lorem"]
+/// stem = "french"
+/// ```
+///
+/// Author profiles accept the options of the [AuthorCleaner](struct.AuthorCleaner.html) :
+///
+/// ```toml
+/// version = 1
+/// cleaner = "author"
+/// keep_roles = true
+/// keep_honorifics = false
+/// canonical_order = "surname_first"
+/// particles = ["german", "fr"]
+/// particle_policy = "drop"
+/// ```
+///
+/// ```
+/// # use b_cleaner::{CleanerConfig, TitleCleaner};
+/// # fn main() {
+/// let config = CleanerConfig::from_toml(r#"
+///     version = 1
+///     cleaner = "title"
+///     split_subtitle = false
+/// "#).unwrap();
+///
+/// let mut title = TitleCleaner::from_str("Lorem ipsum dolor: sit amet");
+/// title.clean_with(&config.pipeline().unwrap());
+///
+/// assert_eq!(title.tokens(), &vec!["lorem", "ipsum", "dolor", "amet"]);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CleanerConfig {
+    /// Version of the profile format
+    pub version: u32,
+    /// Cleaner the profile is based on
+    #[serde(default)]
+    pub cleaner: CleanerKind,
+    /// Tokens smaller or equal to this threshold are removed, defaults to 3 for text and title, disabled for author
+    #[serde(default)]
+    pub token_min_lenght: Option<usize>,
+    /// Pairs of delimiters, tokens between delimiters are removed. Defaults to parentheses and brackets for title and author
+    #[serde(default)]
+    pub delimiters: Option<Vec<(String, String)>>,
+    /// Removes the subtitle (title only)
+    #[serde(default = "default_true")]
+    pub split_subtitle: bool,
+    /// Keeps the subtitle apart instead of discarding it (title only)
+    #[serde(default)]
+    pub keep_subtitle: bool,
+    /// Converts (`convert`) or removes (`remove`) Roman numerals, numerals are kept as words by default, converted numerals are kept whatever the `numbers` policy
+    #[serde(default)]
+    pub roman_numerals: Option<RomanNumerals>,
+    /// What to do with numbers (`drop`, `keep`, `keep_standalone`, `placeholder`), text and title only. Defaults to `drop`
+    #[serde(default)]
+    pub numbers: NumericPolicy,
+    /// Languages of the bundled stopword lists to remove
+    #[serde(default)]
+    pub stopwords: Vec<Language>,
+    /// User supplied stopwords
+    #[serde(default)]
+    pub extra_stopwords: Vec<String>,
+    /// Stemming language (stem feature)
+    #[serde(default)]
+    pub stem: Option<StemLanguage>,
+    /// Keeps the role terms as name tokens (author only)
+    #[serde(default)]
+    pub keep_roles: bool,
+    /// Keeps the honorifics and the suffix as name tokens (author only)
+    #[serde(default)]
+    pub keep_honorifics: bool,
+    /// Outputs names in canonical order, names without comma are read with this policy (`forenames_first`, `surname_first`, `uppercase`, `keep`), author only
+    #[serde(default)]
+    pub canonical_order: Option<AmbiguousOrder>,
+    /// Languages of the particles to recognize (author only), defaults to all the supported languages
+    #[serde(default)]
+    pub particles: Option<Vec<Language>>,
+    /// What to do with particles (`attach`, `drop`), author only
+    #[serde(default)]
+    pub particle_policy: ParticlePolicy
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for CleanerConfig {
+    fn default() -> Self {
+        CleanerConfig::new(CleanerKind::default())
+    }
+}
+
+impl CleanerConfig {
+    /// Create a new profile with the default settings of a cleaner
+    pub fn new(cleaner: CleanerKind) -> Self {
+        CleanerConfig {
+            version: CONFIG_VERSION,
+            cleaner,
+            token_min_lenght: None,
+            delimiters: None,
+            split_subtitle: true,
+            keep_subtitle: false,
+            roman_numerals: None,
+            numbers: NumericPolicy::Drop,
+            stopwords: Vec::new(),
+            extra_stopwords: Vec::new(),
+            stem: None,
+            keep_roles: false,
+            keep_honorifics: false,
+            canonical_order: None,
+            particles: None,
+            particle_policy: ParticlePolicy::Attach
+        }
+    }
+
+    /// Load a profile from a TOML string
+    pub fn from_toml(input: &str) -> Result<Self, ConfigError> {
+        toml::from_str::<CleanerConfig>(input)?.check()
+    }
+
+    /// Load a profile from a JSON string
+    ///
+    /// ```
+    /// # use b_cleaner::{CleanerConfig, CleanerKind};
+    /// # fn main() {
+    /// let config = CleanerConfig::from_json(r#"{"version": 1, "cleaner": "author"}"#).unwrap();
+    ///
+    /// assert_eq!(config.cleaner, CleanerKind::Author);
+    /// # }
+    /// ```
+    pub fn from_json(input: &str) -> Result<Self, ConfigError> {
+        serde_json::from_str::<CleanerConfig>(input)?.check()
+    }
+
+    /// Load a profile from a `.toml` or a `.json` file
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)?;
+
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => CleanerConfig::from_toml(&content),
+            Some("json") => CleanerConfig::from_json(&content),
+            _ => Err(ConfigError::UnknownFormat(path.display().to_string()))
+        }
+    }
+
+    fn check(self) -> Result<Self, ConfigError> {
+        if self.version == 0 || self.version > CONFIG_VERSION {
+            return Err(ConfigError::UnsupportedVersion(self.version));
+        }
+
+        self.check_options()?;
+
+        Ok(self)
+    }
+
+    /// Options set to a value other than their default are rejected when they don't apply to the cleaner
+    fn check_options(&self) -> Result<(), ConfigError> {
+        let title = self.cleaner == CleanerKind::Title;
+        let author = self.cleaner == CleanerKind::Author;
+
+        let inapplicable = [
+            ("split_subtitle", !title && !self.split_subtitle),
+            ("keep_subtitle", !title && self.keep_subtitle),
+            ("numbers", author && self.numbers != NumericPolicy::Drop),
+            ("keep_roles", !author && self.keep_roles),
+            ("keep_honorifics", !author && self.keep_honorifics),
+            ("canonical_order", !author && self.canonical_order.is_some()),
+            ("particles", !author && self.particles.is_some()),
+            ("particle_policy", !author && self.particle_policy != ParticlePolicy::Attach)
+        ];
+
+        match inapplicable.iter().find(|(_, inapplicable)| *inapplicable) {
+            Some((option, _)) => Err(ConfigError::InapplicableOption(option, self.cleaner)),
+            None => Ok(())
+        }
+    }
+
+    /// Create a TitleCleaner with the options of the profile, it must be cleaned with the [pipeline](#method.pipeline) of the profile
+    pub fn title_cleaner<'a, R: AsRef<str>>(&self, input: &'a [R]) -> TitleCleaner<'a> {
+        let mut title = TitleCleaner::new(input);
+        self.configure_title(&mut title);
+
+        title
+    }
+
+    /// Create an AuthorCleaner with the options of the profile, it must be cleaned with the [pipeline](#method.pipeline) of the profile
+    ///
+    /// ```
+    /// # use b_cleaner::CleanerConfig;
+    /// # fn main() {
+    /// let config = CleanerConfig::from_toml(r#"
+    ///     version = 1
+    ///     cleaner = "author"
+    ///     canonical_order = "forenames_first"
+    ///     particle_policy = "drop"
+    /// "#).unwrap();
+    ///
+    /// let tokens = vec!["Ludwig", "van", "Beethoven"];
+    /// let mut author = config.author_cleaner(&tokens);
+    /// author.clean_with(&config.pipeline().unwrap());
+    ///
+    /// assert_eq!(author.tokens(), &vec!["beethoven", "ludwig"]);
+    /// # }
+    /// ```
+    pub fn author_cleaner<'a, R: AsRef<str>>(&self, input: &'a [R]) -> AuthorCleaner<'a> {
+        let mut author = AuthorCleaner::new(input);
+        self.configure_author(&mut author);
+
+        author
+    }
+
+    fn configure_title(&self, title: &mut TitleCleaner) {
+        title.keep_subtitle(self.keep_subtitle);
+    }
+
+    fn configure_author(&self, author: &mut AuthorCleaner) {
+        if let Some(order) = self.canonical_order {
+            author.canonical_order(order);
+        }
+
+        if let Some(languages) = &self.particles {
+            author.particles(languages);
+        }
+
+        author.particle_policy(self.particle_policy);
+        author.keep_roles(self.keep_roles);
+        author.keep_honorifics(self.keep_honorifics);
+    }
+
+    /// Build the cleaning pipeline described by the profile
+    pub fn pipeline(&self) -> Result<Pipeline, ConfigError> {
+        self.check_options()?;
+
+        let mut pipeline = Pipeline::new();
+
+        if let Some(roman_numerals) = self.roman_numerals {
+            pipeline.add_step(roman_numerals);
+        }
+
+        if self.cleaner == CleanerKind::Title && self.split_subtitle {
+            pipeline.add_step(SplitAtStrongPunctuation);
+        }
+
+        match &self.delimiters {
+            Some(delimiters) => delimiters.iter().for_each(|(open, close)| {
+                pipeline.add_step(RemoveBetweenDelimiters::new(open.as_str(), close.as_str()));
+            }),
+            None if self.cleaner != CleanerKind::Text => {
+                pipeline.add_step(RemoveBetweenDelimiters::new("(", ")"));
+                pipeline.add_step(RemoveBetweenDelimiters::new("[", "]"));
+            },
+            None => ()
+        }
+
+        match self.cleaner {
+            CleanerKind::Text | CleanerKind::Title => {
+                let token_min_lenght = self.token_min_lenght.unwrap_or(3);
+
+                pipeline.add_step(MinLength(token_min_lenght));
+                pipeline.add_normalization_steps();
+                pipeline.set_numeric_policy(self.numbers.with_roman_numerals(self.roman_numerals), token_min_lenght);
+            },
+            CleanerKind::Author => {
+                if let Some(token_min_lenght) = self.token_min_lenght {
+                    pipeline.add_step(MinLength(token_min_lenght));
+                }
+                pipeline.add_author_normalization_steps();
+            }
+        }
+
+        if !self.stopwords.is_empty() || !self.extra_stopwords.is_empty() {
+            let mut stopwords = RemoveStopwords::new(&self.stopwords);
+            stopwords.add_words(&self.extra_stopwords);
+            pipeline.add_stopwords(&stopwords);
+        }
+
+        pipeline.add_step(RemoveEmpty);
+
+        if let Some(lang) = self.stem {
+            add_stem_step(&mut pipeline, lang)?;
+        }
+
+        Ok(pipeline)
+    }
+}
+
+/// Tokens cleaned by a [Profile](struct.Profile.html)
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CleanedRecord<'a> {
+    /// The cleaned tokens
+    pub tokens: Vec<Cow<'a, str>>,
+    /// The cleaned subtitle, empty unless the profile is based on the title cleaner and keeps the subtitle
+    pub subtitle: Vec<Cow<'a, str>>
+}
+
+/// A loaded profile, its pipeline is built once and shared by every record it cleans
+///
+/// ```
+/// # use b_cleaner::{CleanerConfig, Profile};
+/// # fn main() {
+/// let config = CleanerConfig::from_toml(r#"
+///     version = 1
+///     cleaner = "title"
+///     keep_subtitle = true
+/// "#).unwrap();
+///
+/// let profile = Profile::new(config).unwrap();
+/// let record = profile.clean(&["Lorem", "ipsum", ":", "dolor"]);
+///
+/// assert_eq!(record.tokens, vec!["lorem", "ipsum"]);
+/// assert_eq!(record.subtitle, vec!["dolor"]);
+/// assert_eq!(profile.clean_str("Dolor amet").tokens, vec!["dolor", "amet"]);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Profile {
+    config: CleanerConfig,
+    pipeline: Pipeline
+}
+
+impl Profile {
+    /// Create a new Profile, the pipeline of the profile is built
+    pub fn new(config: CleanerConfig) -> Result<Self, ConfigError> {
+        let pipeline = config.pipeline()?;
+
+        Ok(Profile { config, pipeline })
+    }
+
+    /// Load a profile from a `.toml` or a `.json` file
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+        Profile::new(CleanerConfig::from_file(path)?)
+    }
+
+    /// Get the configuration of the profile
+    pub fn config(&self) -> &CleanerConfig {
+        &self.config
+    }
+
+    /// Get the pipeline of the profile
+    pub fn pipeline(&self) -> &Pipeline {
+        &self.pipeline
+    }
+
+    /// Clean tokens with the cleaner the profile is based on and its options
+    pub fn clean<'a, R: AsRef<str>>(&self, input: &'a [R]) -> CleanedRecord<'a> {
+        self.clean_tokens(input.iter().map(|token| Cow::Borrowed(token.as_ref())))
+    }
+
+    /// Clean a raw string with the cleaner the profile is based on and its options, the string is tokenized with the [Tokenizer](struct.Tokenizer.html)
+    pub fn clean_str<'a>(&self, input: &'a str) -> CleanedRecord<'a> {
+        self.clean_tokens(Tokenizer::new(input).map(Cow::Borrowed))
+    }
+
+    fn clean_tokens<'a, I: Iterator<Item = Cow<'a, str>>>(&self, tokens: I) -> CleanedRecord<'a> {
+        match self.config.cleaner {
+            CleanerKind::Text => {
+                let mut text = TextCleaner::with_tokens(tokens.collect());
+                text.clean_with(&self.pipeline);
+
+                CleanedRecord { tokens: text.tokens().clone(), subtitle: Vec::new() }
+            },
+            CleanerKind::Title => {
+                let mut title = TitleCleaner::with_tokens(tokens.collect());
+                self.config.configure_title(&mut title);
+                title.clean_with(&self.pipeline);
+
+                CleanedRecord { tokens: title.tokens().clone(), subtitle: title.subtitle().clone() }
+            },
+            CleanerKind::Author => {
+                let mut author = AuthorCleaner::with_tokens(tokens);
+                self.config.configure_author(&mut author);
+                author.clean_with(&self.pipeline);
+
+                CleanedRecord { tokens: author.into_tokens(), subtitle: Vec::new() }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "stem")]
+fn add_stem_step(pipeline: &mut Pipeline, lang: StemLanguage) -> Result<(), ConfigError> {
+    pipeline.add_step(Stem::new(lang.into()));
+
+    Ok(())
+}
+
+#[cfg(not(feature = "stem"))]
+fn add_stem_step(_pipeline: &mut Pipeline, lang: StemLanguage) -> Result<(), ConfigError> {
+    Err(ConfigError::StemmingDisabled(lang))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+
+    #[test]
+    fn test_default_profiles_match_presets() {
+        assert_eq!(CleanerConfig::new(CleanerKind::Text).pipeline().unwrap().step_names(), Pipeline::text(3).step_names());
+        assert_eq!(CleanerConfig::new(CleanerKind::Title).pipeline().unwrap().step_names(), Pipeline::title(3).step_names());
+        assert_eq!(CleanerConfig::new(CleanerKind::Author).pipeline().unwrap().step_names(), Pipeline::author().step_names());
+    }
+
+    #[test]
+    fn test_toml_and_json_profiles() {
+        let toml = CleanerConfig::from_toml(r#"
+            version = 1
+            cleaner = "title"
+            token_min_lenght = 2
+            delimiters = [["(", ")"]]
+        "#).unwrap();
+
+        let json = CleanerConfig::from_json(r#"{"version": 1, "cleaner": "title", "token_min_lenght": 2, "delimiters": [["(", ")"]]}"#).unwrap();
+
+        assert_eq!(toml, json);
+        assert_eq!(toml.pipeline().unwrap().position("remove_between_delimiters"), Some(1));
+    }
+
+    #[test]
+    fn test_stopwords_profile() {
+        let config = CleanerConfig::from_toml(r#"
+            version = 1
+            stopwords = ["fr", "english"]
+            extra_stopwords = ["Lorem"]
+        "#).unwrap();
+
+        let mut tokens = vec![Cow::Borrowed("Lorem"), Cow::Borrowed("about"), Cow::Borrowed("dans"), Cow::Borrowed("ipsum")];
+        config.pipeline().unwrap().apply(&mut tokens);
+
+        assert_eq!(tokens, vec!["ipsum"]);
+    }
+
+    #[test]
+    fn test_roman_numerals_profile() {
+        let config = CleanerConfig::from_json(r#"{"version": 1, "cleaner": "text", "roman_numerals": "remove"}"#).unwrap();
+
+        let mut tokens = vec![Cow::Borrowed("Louis"), Cow::Borrowed("XVIII"), Cow::Borrowed("Bourbon")];
+        config.pipeline().unwrap().apply(&mut tokens);
+
+        assert_eq!(config.roman_numerals, Some(RomanNumerals::Remove));
+        assert_eq!(config.numbers, NumericPolicy::Drop);
+        assert_eq!(tokens, vec!["louis", "bourbon"]);
+    }
+
+    #[test]
+    fn test_invalid_profiles() {
+        assert!(matches!(CleanerConfig::from_toml("version = 99"), Err(ConfigError::UnsupportedVersion(99))));
+        assert!(matches!(CleanerConfig::from_toml("version = 1\nunknown = true"), Err(ConfigError::Toml(_))));
+        assert!(matches!(CleanerConfig::from_json(r#"{"cleaner": "title"}"#), Err(ConfigError::Json(_))));
+
+        assert!(matches!(CleanerConfig::from_toml("version = 1\nstem = \"klingon\""), Err(ConfigError::Toml(_))));
+    }
+
+    #[test]
+    fn test_stem_profile() {
+        let config = CleanerConfig::from_json(r#"{"version": 1, "stem": "french"}"#).unwrap();
+
+        assert_eq!(config.stem, Some(StemLanguage::French));
+
+        #[cfg(feature = "stem")]
+        assert_eq!(config.pipeline().unwrap().step_names().last(), Some(&"stem"));
+
+        #[cfg(not(feature = "stem"))]
+        assert!(matches!(config.pipeline(), Err(ConfigError::StemmingDisabled(StemLanguage::French))));
+    }
+
+    #[test]
+    fn test_inapplicable_options() {
+        assert!(matches!(CleanerConfig::from_json(r#"{"version": 1, "cleaner": "author", "numbers": "keep"}"#), Err(ConfigError::InapplicableOption("numbers", CleanerKind::Author))));
+        assert!(matches!(CleanerConfig::from_toml("version = 1\nkeep_subtitle = true"), Err(ConfigError::InapplicableOption("keep_subtitle", CleanerKind::Text))));
+        assert!(matches!(CleanerConfig::from_json(r#"{"version": 1, "cleaner": "title", "keep_roles": true}"#), Err(ConfigError::InapplicableOption("keep_roles", CleanerKind::Title))));
+
+        let config = CleanerConfig {
+            particle_policy: ParticlePolicy::Drop,
+            ..CleanerConfig::default()
+        };
+        assert!(matches!(config.pipeline(), Err(ConfigError::InapplicableOption("particle_policy", CleanerKind::Text))));
+    }
+
+    #[test]
+    fn test_author_profile() {
+        let config = CleanerConfig::from_toml(r#"
+            version = 1
+            cleaner = "author"
+            keep_roles = true
+            particles = ["english", "dutch"]
+            particle_policy = "drop"
+        "#).unwrap();
+        let profile = Profile::new(config).unwrap();
+
+        assert_eq!(profile.clean(&["Dupont,", "Jean,", "dir."]).tokens, vec!["dupont", "jean", "dir"]);
+        assert_eq!(profile.clean(&["Beethoven,", "Ludwig", "van"]).tokens, vec!["beethoven", "ludwig"]);
+        assert_eq!(profile.clean(&["Dupont,", "Jean", "de"]).tokens, vec!["dupont", "jean", "de"]);
+        assert_eq!(profile.clean_str("Beethoven, Ludwig van").tokens, vec!["beethoven", "ludwig"]);
+    }
+}
@@ -0,0 +1,327 @@
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use crate::cleaners::Clean;
+use crate::language::Language;
+use crate::pipeline::*;
+use crate::place::normalize_place;
+use crate::stopwords::RemoveStopwords;
+use crate::tokenizer::Tokenizer;
+
+#[cfg(feature = "stem")]
+use rust_stemmers::Algorithm;
+
+/// Common words of corporate names and their variants
+const CORPORATE_TERMS: &[(&str, &[&str])] = &[
+    ("university", &["universite", "universitat", "universidad", "universita", "universiteit", "univ", "uni"]),
+    ("faculty", &["faculte", "fakultat", "facultad", "facolta", "fac"]),
+    ("department", &["departement", "departamento", "dipartimento", "dept", "dep"]),
+    ("institute", &["institut", "instituto", "istituto", "inst"]),
+    ("laboratory", &["laboratoire", "laboratorio", "labor", "labo", "lab"]),
+    ("ministry", &["ministere", "ministerio", "ministero", "ministerium", "min"]),
+    ("society", &["societe", "sociedad", "societa", "soc"]),
+    ("association", &["asociacion", "associazione", "assoc"]),
+    ("academy", &["academie", "akademie", "academia", "acad"]),
+    ("library", &["bibliotheque", "bibliothek", "biblioteca", "bibl"]),
+    ("school", &["ecole", "escuela", "scuola", "schule"]),
+    ("college", &["collegio", "coll"]),
+    ("national", &["nationale", "nacional", "nazionale", "natl", "nat"]),
+    ("international", &["internationale", "internacional", "internazionale", "intl"]),
+    ("center", &["centre", "centro", "zentrum", "ctr"])
+];
+
+/// Abbreviations followed by a dot which doesn't end a hierarchy level (`Univ. de Paris`)
+const ABBREVIATIONS: &[&str] = &[
+    "univ", "fac", "dept", "dep", "inst", "lab", "labo", "min", "soc", "assoc", "acad", "bibl", "coll", "natl", "nat", "intl", "ctr",
+    "st", "ste", "cie", "co", "inc", "ltd", "corp", "admin", "serv", "dir"
+];
+
+/// Jurisdictions (countries, regions and cities) which can head a corporate name (`France. Ministère de la culture`), compared once normalized
+const JURISDICTIONS: &[&str] = &[
+    "France", "Belgique", "Belgium", "België", "Suisse", "Switzerland", "Schweiz", "Svizzera", "Luxembourg", "Monaco",
+    "Germany", "Allemagne", "Deutschland", "Austria", "Autriche", "Österreich", "Italy", "Italie", "Italia",
+    "Spain", "Espagne", "España", "Portugal", "Netherlands", "Pays-Bas", "Nederland", "Ireland", "Irlande",
+    "United Kingdom", "Royaume-Uni", "Great Britain", "Grande-Bretagne", "England", "Angleterre", "Scotland", "Écosse", "Wales",
+    "United States", "États-Unis", "Canada", "Québec", "Quebec", "Ontario", "Mexico", "Mexique", "Brazil", "Brésil", "Brasil",
+    "Argentina", "Argentine", "Japan", "Japon", "China", "Chine", "India", "Inde", "Russia", "Russie",
+    "Poland", "Pologne", "Polska", "Sweden", "Suède", "Sverige", "Norway", "Norvège", "Norge", "Denmark", "Danemark", "Danmark",
+    "Finland", "Finlande", "Greece", "Grèce", "Turkey", "Turquie", "Morocco", "Maroc", "Algeria", "Algérie", "Tunisia", "Tunisie",
+    "Sénégal", "Australia", "Australie", "European Union", "Union européenne",
+    "Île-de-France", "Bretagne", "Normandie", "Alsace", "Occitanie", "Corse", "Grand Est", "Hauts-de-France", "Nouvelle-Aquitaine",
+    "Auvergne-Rhône-Alpes", "Bourgogne-Franche-Comté", "Centre-Val de Loire", "Pays de la Loire", "Provence-Alpes-Côte d'Azur",
+    "Wallonie", "Vlaanderen", "Flandre", "Bayern", "Bavaria", "Bavière", "Baden-Württemberg", "Nordrhein-Westfalen", "Sachsen", "Hessen",
+    "Catalunya", "Catalogne", "California", "Californie", "Texas", "Massachusetts",
+    "Paris", "Lyon", "Marseille", "Toulouse", "Bordeaux", "Lille", "Strasbourg", "Nantes", "London", "Londres", "Berlin",
+    "Wien", "Vienne", "Roma", "Rome", "Madrid", "Bruxelles", "Brussels", "Genève", "Geneva", "New York"
+];
+
+#[derive(Debug, Clone)]
+/// A struct dedicated to corporate bodies cleaning (MARC 110 / UNIMARC 710)
+///
+/// A corporate name is split into hierarchy levels separated by `.` (`Université de Paris. Faculté de médecine`), a dot following an abbreviation (`Univ.`) or an initial doesn't end a level.
+///
+/// Each level is cleaned by the [corporate pipeline](struct.Pipeline.html#method.corporate), then common words are replaced by a canonical form (`Université`, `Univ.` become `university`) and stopwords of all the supported languages are removed.
+///
+/// ```
+/// # use b_cleaner::{CorporateCleaner, Clean};
+/// # fn main() {
+/// let mut corporate = CorporateCleaner::from_str("Université de Paris. Faculté de médecine");
+///
+/// corporate.clean();
+///
+/// assert_eq!(corporate.levels(), &vec![vec!["university", "paris"], vec!["faculty", "medecine"]]);
+/// assert_eq!(corporate.keys(), vec!["university paris", "university paris. faculty medecine"]);
+/// assert_eq!(corporate.keys(), CorporateCleaner::from_str("Univ. of Paris. Faculty of medecine").clean().keys());
+/// # }
+/// ```
+pub struct CorporateCleaner<'a> {
+    levels: Vec<Vec<Cow<'a, str>>>,
+    jurisdiction: Vec<Cow<'a, str>>,
+    remove_jurisdiction: bool,
+    synonyms: ReplaceWords,
+    stopwords: RemoveStopwords,
+    customized: bool
+}
+
+impl <'a>CorporateCleaner<'a> {
+    /// Create a new CorporateCleaner
+    ///
+    /// ```
+    /// # use b_cleaner::CorporateCleaner;
+    /// # fn main() {
+    /// let tokens = vec!["France.", "Ministère", "de", "la", "culture"];
+    /// let corporate = CorporateCleaner::new(&tokens);
+    ///
+    /// assert_eq!(corporate.levels(), &vec![vec!["France."], vec!["Ministère", "de", "la", "culture"]]);
+    /// # }
+    /// ```
+    pub fn new<R: AsRef<str>>(input: &'a [R]) -> Self {
+        CorporateCleaner::with_tokens(input.iter().map(|token| Cow::Borrowed(token.as_ref())))
+    }
+
+    /// Create a new CorporateCleaner from a raw string, the string is tokenized with the [Tokenizer](struct.Tokenizer.html)
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(input: &'a str) -> Self {
+        CorporateCleaner::with_tokens(Tokenizer::new(input).map(Cow::Borrowed))
+    }
+
+    fn with_tokens<I: Iterator<Item = Cow<'a, str>>>(tokens: I) -> Self {
+        let mut synonyms = ReplaceWords::new();
+
+        CORPORATE_TERMS.iter().for_each(|(canonical, variants)| {
+            synonyms.add_synonyms(canonical, variants);
+        });
+
+        CorporateCleaner {
+            levels: split_levels(tokens),
+            jurisdiction: Vec::new(),
+            remove_jurisdiction: false,
+            synonyms,
+            stopwords: RemoveStopwords::new(&Language::ALL),
+            customized: false
+        }
+    }
+
+    /// Get the hierarchy levels, from the highest to the lowest
+    pub fn levels(&self) -> &Vec<Vec<Cow<'a, str>>> {
+        &self.levels
+    }
+
+    /// Get the jurisdiction removed while cleaning
+    pub fn jurisdiction(&self) -> &Vec<Cow<'a, str>> {
+        &self.jurisdiction
+    }
+
+    /// Remove jurisdiction qualifiers while cleaning
+    ///
+    /// Qualifiers between parentheses (`Bibliothèque nationale (France)`) are jurisdictions, as well as the first level if it has subordinate levels and is a known country, region or city (`France. Ministère de la culture`).
+    ///
+    /// ```
+    /// # use b_cleaner::{CorporateCleaner, Clean};
+    /// # fn main() {
+    /// let mut corporate = CorporateCleaner::from_str("France. Ministère de la culture");
+    /// corporate.remove_jurisdiction(true);
+    ///
+    /// corporate.clean();
+    ///
+    /// assert_eq!(corporate.levels(), &vec![vec!["ministry", "culture"]]);
+    /// assert_eq!(corporate.jurisdiction(), &vec!["france"]);
+    /// # }
+    /// ```
+    pub fn remove_jurisdiction(&mut self, remove: bool) -> &Self {
+        self.remove_jurisdiction = remove;
+        self
+    }
+
+    /// Add user supplied variants of a common word
+    pub fn add_synonyms<S: AsRef<str>>(&mut self, canonical: &str, variants: &[S]) -> &Self {
+        self.synonyms.add_synonyms(canonical, variants);
+        self.customized = true;
+        self
+    }
+
+    /// Remove the stopwords of some languages only, stopwords of all the supported languages are removed by default
+    pub fn stopwords(&mut self, languages: &[Language]) -> &Self {
+        self.stopwords = RemoveStopwords::new(languages);
+        self.customized = true;
+        self
+    }
+
+    /// Get the pipeline used to clean each level, common words are replaced and stopwords removed after the normalization steps
+    pub fn pipeline(&self) -> Pipeline {
+        let mut pipeline = Pipeline::corporate();
+
+        pipeline.insert_step(pipeline.len() - 1, self.synonyms.clone());
+        pipeline.insert_step(pipeline.len() - 1, self.stopwords.clone());
+
+        pipeline
+    }
+
+    /// Clean each level with a custom [Pipeline](struct.Pipeline.html) instead of the preset one
+    pub fn clean_with(&mut self, pipeline: &Pipeline) -> &Self {
+        if self.remove_jurisdiction {
+            self.split_off_jurisdiction();
+            pipeline.apply(&mut self.jurisdiction);
+        }
+
+        self.levels.iter_mut().for_each(|level| pipeline.apply(level));
+        self.levels.retain(|level| !level.is_empty());
+
+        self
+    }
+
+    /// Build a key for each hierarchy level, made of the level and all the levels above it
+    pub fn keys(&self) -> Vec<String> {
+        let levels : Vec<String> = self.levels.iter().map(|level| level.join(" ")).collect();
+
+        (1..=levels.len()).map(|depth| levels[..depth].join(". ")).collect()
+    }
+
+    fn split_off_jurisdiction(&mut self) {
+        for level in self.levels.iter_mut() {
+            let mut qualifier = None;
+            let mut index = 0;
+
+            while index < level.len() {
+                if level[index].starts_with('(') {
+                    qualifier = Some(index);
+                }
+
+                match qualifier {
+                    Some(start) if level[index].trim_end_matches('.').ends_with(')') => {
+                        self.jurisdiction.extend(level.drain(start..=index));
+                        qualifier = None;
+                        index = start;
+                    },
+                    _ => index += 1
+                }
+            }
+        }
+
+        let first_is_jurisdiction = self.levels.len() > 1 && {
+            let mut words = self.levels[0].clone();
+            Pipeline::preset(Preset::Corporate, Pipeline::corporate).apply(&mut words);
+
+            let first = words.join(" ");
+            JURISDICTIONS.iter().any(|jurisdiction| normalize_place(jurisdiction) == first)
+        };
+
+        if first_is_jurisdiction {
+            self.jurisdiction.splice(0..0, self.levels.remove(0));
+        }
+    }
+}
+
+/// Splits tokens into hierarchy levels at dots, dots following an abbreviation or an initial are kept inside the level
+fn split_levels<'a, I: Iterator<Item = Cow<'a, str>>>(tokens: I) -> Vec<Vec<Cow<'a, str>>> {
+    let mut levels = vec![Vec::new()];
+
+    for token in tokens {
+        let level = levels.last_mut().expect("levels can't be empty");
+        let ends_level = token.ends_with('.');
+
+        if token != "." {
+            level.push(token);
+        }
+
+        let abbreviated = level.last().is_some_and(|word| {
+            let word = word.trim_end_matches('.').to_lowercase();
+
+            word.chars().count() == 1 && word.chars().all(char::is_alphabetic) || ABBREVIATIONS.contains(&word.as_str())
+        });
+
+        if ends_level && !abbreviated && !level.is_empty() {
+            levels.push(Vec::new());
+        }
+    }
+
+    levels.retain(|level| !level.is_empty());
+    levels
+}
+
+impl <'a>Clean for CorporateCleaner<'a> {
+    fn clean(&mut self) -> &Self {
+        let pipeline = if self.customized {
+            Rc::new(self.pipeline())
+        } else {
+            Pipeline::preset(Preset::CorporateCleaner, || self.pipeline())
+        };
+
+        self.clean_with(&pipeline)
+    }
+
+    #[cfg(feature = "stem")]
+    fn stem(&mut self, lang: Algorithm) -> &Self {
+        let stem = Stem::new(lang);
+
+        self.levels.iter_mut().for_each(|level| stem.apply(level));
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_levels() {
+        let corporate = CorporateCleaner::from_str("Univ. de Paris. Inst. d'études politiques. Service J. Dupont");
+
+        assert_eq!(corporate.levels().len(), 3);
+        assert_eq!(corporate.levels()[2], vec!["Service", "J", "Dupont"]);
+    }
+
+    #[test]
+    fn test_remove_jurisdiction() {
+        let mut corporate = CorporateCleaner::from_str("Bibliothèque nationale (France). Département des manuscrits");
+        corporate.remove_jurisdiction(true);
+        corporate.clean();
+
+        assert_eq!(corporate.keys(), vec!["library national", "library national. department manuscrits"]);
+        assert_eq!(corporate.jurisdiction(), &vec!["france"]);
+    }
+
+    #[test]
+    fn test_parent_body_is_not_a_jurisdiction() {
+        for (input, key) in [
+            ("CNRS. Laboratoire de physique", "cnrs. laboratory physique"),
+            ("Gallimard. Service de presse", "gallimard. service presse"),
+            ("Renault. Direction des études", "renault. direction etudes")
+        ] {
+            let mut corporate = CorporateCleaner::from_str(input);
+            corporate.remove_jurisdiction(true);
+            corporate.clean();
+
+            assert_eq!(corporate.keys().last().unwrap(), key);
+            assert!(corporate.jurisdiction().is_empty());
+        }
+
+        let mut corporate = CorporateCleaner::from_str("Île-de-France. Conseil régional");
+        corporate.remove_jurisdiction(true);
+        corporate.clean();
+
+        assert_eq!(corporate.keys(), vec!["conseil regional"]);
+        assert_eq!(corporate.jurisdiction(), &vec!["ile-de-france"]);
+    }
+}
@@ -0,0 +1,276 @@
+use std::borrow::Cow;
+
+use crate::author::{Year, is_unknown_digit, join_date_parts, split_range};
+use crate::cleaners::Clean;
+use crate::roman::parse_roman;
+use crate::tokenizer::Tokenizer;
+
+#[cfg(feature = "stem")]
+use rust_stemmers::Algorithm;
+
+/// Markers of copyright and phonogram dates (`c1985`, `cop. 2003`, `©2003`, `p1990`), compared without dots. Single letters are only markers when attached to the year, as `318 p.` is a number of pages
+const COPYRIGHT: &[&str] = &["c", "cop", "copr", "copyright", "©", "p", "℗"];
+
+/// Markers of approximate dates (`ca 1850`, `vers 1850`), compared without dots
+const APPROXIMATE: &[&str] = &["ca", "cca", "circa", "approx", "env", "vers", "um", "etwa"];
+
+/// Years written with Roman numerals are only accepted from this year on, to skip short words made of Roman letters (`mix`)
+const MIN_ROMAN_YEAR: u32 = 1400;
+
+/// Years written with Roman numerals are only accepted up to this year
+const MAX_ROMAN_YEAR: u32 = 2100;
+
+/// Chars separating dates in an imprint
+const SEPARATORS: &[char] = &['[', ']', '(', ')', ',', ';', ':'];
+
+#[derive(Debug, Clone, Copy)]
+/// A date found in an imprint
+struct PublicationDate {
+    year: Year,
+    uncertain: bool,
+    copyright: bool
+}
+
+#[derive(Debug, Clone)]
+/// A struct dedicated to publication dates cleaning
+///
+/// Imprint dates are parsed into a [Year](struct.Year.html), a range of publication (`1999-2001`) or an imprecise date (`18..`) is represented by its earliest and latest years.
+///
+/// Supported forms are years (`1998`, `[1998?]`), copyright dates (`c1985`, `cop. 2003`, `©2003`), approximate dates (`ca 1850`, `vers 1850`), imprecise years (`18..`, `195-?`, `19XX`), years written with Roman numerals (`MCMLXXX`, `M.DCC.LXXX`) and ranges (`1999-2001`, `1987-88`). When an imprint gives both a publication date and a copyright date (`2003, cop. 1999`), the publication date is kept.
+///
+/// ```
+/// # use b_cleaner::{DateCleaner, Year, Clean};
+/// # fn main() {
+/// let mut date = DateCleaner::from_str("[1998?]");
+///
+/// date.clean();
+///
+/// assert_eq!(date.year(), Some(Year::new(1998)));
+/// assert!(date.uncertain());
+///
+/// let mut date = DateCleaner::from_str("cop. 2003");
+/// date.clean();
+///
+/// assert_eq!(date.year(), Some(Year::new(2003)));
+/// assert!(date.copyright());
+///
+/// assert_eq!(DateCleaner::from_str("MCMLXXX").clean().year(), Some(Year::new(1980)));
+/// assert_eq!(DateCleaner::from_str("1999-2001").clean().key().as_deref(), Some("1999-2001"));
+/// # }
+/// ```
+pub struct DateCleaner<'a> {
+    tokens: Vec<Cow<'a, str>>,
+    date: Option<PublicationDate>
+}
+
+impl <'a>DateCleaner<'a> {
+    /// Create a new DateCleaner
+    ///
+    /// ```
+    /// # use b_cleaner::{DateCleaner, Year, Clean};
+    /// # fn main() {
+    /// let tokens = vec!["18", ".."];
+    /// let mut date = DateCleaner::new(&tokens);
+    ///
+    /// date.clean();
+    ///
+    /// assert_eq!(date.year(), Some(Year::between(1800, 1899)));
+    /// assert!(date.uncertain());
+    /// # }
+    /// ```
+    pub fn new<R: AsRef<str>>(input: &'a [R]) -> Self {
+        DateCleaner::with_tokens(input.iter().map(|token| Cow::Borrowed(token.as_ref())))
+    }
+
+    /// Create a new DateCleaner from a raw string, the string is tokenized with the [Tokenizer](struct.Tokenizer.html)
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(input: &'a str) -> Self {
+        DateCleaner::with_tokens(Tokenizer::new(input).map(Cow::Borrowed))
+    }
+
+    fn with_tokens<I: Iterator<Item = Cow<'a, str>>>(tokens: I) -> Self {
+        DateCleaner {
+            tokens: tokens.collect(),
+            date: None
+        }
+    }
+
+    /// Get the tokens
+    pub fn tokens(&self) -> &Vec<Cow<'a, str>> {
+        &self.tokens
+    }
+
+    /// Get the publication year, None if no date is found or before cleaning
+    pub fn year(&self) -> Option<Year> {
+        self.date.map(|date| date.year)
+    }
+
+    /// Returns true if the date is uncertain (`1998?`, `ca 1850`) or imprecise (`18..`)
+    pub fn uncertain(&self) -> bool {
+        self.date.is_some_and(|date| date.uncertain)
+    }
+
+    /// Returns true if the date is a copyright date (`c1985`)
+    pub fn copyright(&self) -> bool {
+        self.date.is_some_and(|date| date.copyright)
+    }
+
+    /// Build a key out of the year, ranges and imprecise years are written `earliest-latest`
+    pub fn key(&self) -> Option<String> {
+        self.year().map(|year| match year.exact() {
+            Some(year) => year.to_string(),
+            None => format!("{}-{}", year.earliest(), year.latest())
+        })
+    }
+}
+
+/// Parses the dates of an imprint, markers apply to the following date
+///
+/// Years written with Roman numerals are only kept when the imprint has no year written with digits, as acronyms of publishers (`MM éditions`, `Éd. MD`) are made of Roman letters
+fn parse_dates(tokens: &[Cow<str>]) -> Vec<PublicationDate> {
+    let text : String = tokens.join(" ")
+        .chars()
+        .map(|c| if SEPARATORS.contains(&c) { ' ' } else { c })
+        .collect();
+
+    let mut dates = Vec::new();
+    let mut roman_dates = Vec::new();
+    let mut copyright = false;
+    let mut approximate = false;
+
+    for original in join_date_parts(&text).split_whitespace() {
+        let word = original.to_lowercase();
+        let (marker, rest) = word.split_at(word.find(|c: char| c.is_ascii_digit()).unwrap_or(word.len()));
+        let marker = marker.trim_end_matches('.');
+
+        let standalone_letter = rest.is_empty() && marker.chars().count() == 1 && marker.chars().all(char::is_alphabetic);
+
+        if COPYRIGHT.contains(&marker) && !standalone_letter {
+            copyright = true;
+        } else if APPROXIMATE.contains(&marker) {
+            approximate = true;
+        } else if !marker.is_empty() && !rest.is_empty() {
+            continue;
+        }
+
+        let (date, found) = match rest {
+            "" => (parse_roman_year(original).map(|year| (year, false)), &mut roman_dates),
+            rest => (parse_date(rest), &mut dates)
+        };
+
+        if let Some((year, uncertain)) = date {
+            found.push(PublicationDate {
+                year,
+                uncertain: uncertain || approximate,
+                copyright
+            });
+
+            copyright = false;
+            approximate = false;
+        }
+    }
+
+    if dates.is_empty() { roman_dates } else { dates }
+}
+
+/// Parses a year or a range of years, returns the year and true if it's uncertain
+fn parse_date(word: &str) -> Option<(Year, bool)> {
+    let (start, end) = split_range(word);
+    let start = start.map(strip_question_mark).filter(|start| date_len(start) >= 4)?;
+    let start = Year::parse(start)?;
+
+    let end = end.map(strip_question_mark).and_then(Year::parse).map(|end| match end.exact() {
+        Some(0..=9) => Year::new(start.earliest() - start.earliest() % 10 + end.earliest()),
+        Some(10..=99) => Year::new(start.earliest() - start.earliest() % 100 + end.earliest()),
+        _ => end
+    });
+
+    let uncertain = word.contains('?') || start.exact().is_none() || end.is_some_and(|end| end.exact().is_none());
+    let year = Year::between(start.earliest(), end.map_or(start.latest(), |end| end.latest()));
+
+    Some((year, uncertain))
+}
+
+/// Number of digits and unknown digits at the start of a date
+fn date_len(date: &str) -> usize {
+    date.chars().take_while(|c| c.is_ascii_digit() || is_unknown_digit(*c)).count()
+}
+
+/// Removes the question mark of an uncertain year (`1998?`, `195-?`), unless it stands for the last digit (`195?`)
+fn strip_question_mark(date: &str) -> &str {
+    match date.strip_suffix('?') {
+        Some(stripped) if date_len(stripped) >= 4 => stripped,
+        _ => date
+    }
+}
+
+/// Parses a year written with Roman numerals in uppercase, dots are ignored (`M.DCC.LXXX`)
+fn parse_roman_year(word: &str) -> Option<Year> {
+    let numerals : String = word.chars().filter(|c| *c != '.').collect();
+
+    if numerals.chars().any(|c| !c.is_ascii_uppercase()) {
+        return None;
+    }
+
+    parse_roman(&numerals)
+        .filter(|year| (MIN_ROMAN_YEAR..=MAX_ROMAN_YEAR).contains(year))
+        .map(|year| Year::new(year as i32))
+}
+
+impl <'a>Clean for DateCleaner<'a> {
+    /// Parse the publication date, the first date which isn't a copyright date is kept
+    fn clean(&mut self) -> &Self {
+        let dates = parse_dates(&self.tokens);
+
+        self.date = dates.iter().find(|date| !date.copyright).or_else(|| dates.first()).copied();
+
+        self
+    }
+
+    /// Dates are not stemmed
+    #[cfg(feature = "stem")]
+    fn stem(&mut self, _lang: Algorithm) -> &Self {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(input: &str) -> (Option<Year>, bool, bool) {
+        let mut date = DateCleaner::from_str(input);
+        date.clean();
+
+        (date.year(), date.uncertain(), date.copyright())
+    }
+
+    #[test]
+    fn test_imprint_dates() {
+        assert_eq!(date("c1985"), (Some(Year::new(1985)), false, true));
+        assert_eq!(date("2003, ©1999"), (Some(Year::new(2003)), false, false));
+        assert_eq!(date("[ca 1850]"), (Some(Year::new(1850)), true, false));
+        assert_eq!(date("195-?"), (Some(Year::between(1950, 1959)), true, false));
+        assert_eq!(date("1987-88"), (Some(Year::between(1987, 1988)), false, false));
+        assert_eq!(date("A Paris, M.DCC.LXXX."), (Some(Year::new(1780)), false, false));
+        assert_eq!(date("[s.d.]"), (None, false, false));
+        assert_eq!(date("mix 2e éd."), (None, false, false));
+    }
+
+    #[test]
+    fn test_copyright_markers() {
+        assert_eq!(date("Paris : Gallimard, 318 p. 2003"), (Some(Year::new(2003)), false, false));
+        assert_eq!(date("c. 2003"), (Some(Year::new(2003)), false, false));
+        assert_eq!(date("cop. 2003"), (Some(Year::new(2003)), false, true));
+        assert_eq!(date("p1990"), (Some(Year::new(1990)), false, true));
+        assert_eq!(date("© 1999"), (Some(Year::new(1999)), false, true));
+    }
+
+    #[test]
+    fn test_roman_years_next_to_publisher_acronyms() {
+        assert_eq!(date("Paris : MM éditions, 2003"), (Some(Year::new(2003)), false, false));
+        assert_eq!(date("Lyon : Éd. MD, 1999"), (Some(Year::new(1999)), false, false));
+        assert_eq!(date("Lugduni, M.DC.X"), (Some(Year::new(1610)), false, false));
+        assert_eq!(date("mdcclxxx"), (None, false, false));
+    }
+}
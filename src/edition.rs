@@ -0,0 +1,228 @@
+use std::borrow::Cow;
+
+use unidecode::unidecode;
+
+use crate::cleaners::Clean;
+use crate::meeting::parse_ordinal;
+use crate::roman::parse_roman_ordinal;
+use crate::tokenizer::{Tokenizer, is_punctuation};
+
+#[cfg(feature = "stem")]
+use rust_stemmers::Algorithm;
+
+/// Spelled-out ordinals, compared once normalized (lowercase, unidecode)
+pub(crate) const SPELLED_ORDINALS: &[(&str, u32)] = &[
+    // English
+    ("first", 1), ("second", 2), ("third", 3), ("fourth", 4), ("fifth", 5), ("sixth", 6), ("seventh", 7), ("eighth", 8), ("ninth", 9), ("tenth", 10),
+    // French
+    ("premier", 1), ("premiere", 1), ("deuxieme", 2), ("seconde", 2), ("troisieme", 3), ("quatrieme", 4), ("cinquieme", 5), ("sixieme", 6),
+    ("septieme", 7), ("huitieme", 8), ("neuvieme", 9), ("dixieme", 10),
+    // German
+    ("erste", 1), ("ersten", 1), ("zweite", 2), ("zweiten", 2), ("dritte", 3), ("dritten", 3), ("vierte", 4), ("vierten", 4),
+    ("funfte", 5), ("funften", 5), ("sechste", 6), ("sechsten", 6), ("siebte", 7), ("siebten", 7), ("achte", 8), ("achten", 8),
+    ("neunte", 9), ("neunten", 9), ("zehnte", 10), ("zehnten", 10),
+    // Spanish
+    ("primera", 1), ("primero", 1), ("segunda", 2), ("segundo", 2), ("tercera", 3), ("tercero", 3), ("cuarta", 4), ("quinta", 5),
+    ("sexta", 6), ("septima", 7), ("octava", 8), ("novena", 9), ("decima", 10),
+    // Italian
+    ("prima", 1), ("primo", 1), ("seconda", 2), ("secondo", 2), ("terza", 3), ("terzo", 3), ("quarta", 4), ("sesta", 6),
+    ("settima", 7), ("ottava", 8), ("nona", 9)
+];
+
+/// Markers of revised editions, compared once normalized without the final dot
+const REVISED: &[&str] = &[
+    "rev", "revised", "revue", "revu", "revisee", "corr", "corrected", "corrigee", "corrige", "updated", "actualisee",
+    "uberarb", "uberarbeitete", "uberarbeitet", "neubearb", "neubearbeitete", "bearb", "korr", "korrigierte", "aktualisierte",
+    "revisada", "revisado", "corregida", "actualizada", "riv", "riveduta", "corretta", "aggiornata"
+];
+
+/// Markers of augmented editions, compared once normalized without the final dot
+const AUGMENTED: &[&str] = &[
+    "augm", "aug", "augmentee", "augmente", "augmented", "enl", "enlarged", "expanded", "erw", "erweiterte", "erweitert",
+    "verm", "vermehrte", "ampl", "ampliada", "ampliado", "aum", "aumentada", "ampliata", "accr", "accresciuta"
+];
+
+/// Markers of reprints, compared once normalized without the final dot
+const REPRINT: &[&str] = &[
+    "reprint", "reprinted", "repr", "reimpr", "reimpression", "reimp", "reimpresion", "nachdr", "nachdruck", "ristampa", "facsim", "facsimile"
+];
+
+/// Words following the number of a printing (`2e tirage`), which isn't the number of an edition
+const PRINTING: &[&str] = &["tirage", "impr", "impression", "printing", "print", "druck", "ristampa", "reimpr", "reimpression", "reimpresion"];
+
+/// Editions written with Roman numerals are only accepted up to this number, to skip words written in uppercase (`DE`)
+const MAX_ROMAN_EDITION: u32 = 100;
+
+/// A word of an edition statement
+struct Word<'a> {
+    token: &'a str,
+    text: String
+}
+
+#[derive(Debug, Clone)]
+/// A struct dedicated to edition statements cleaning
+///
+/// The number of the edition is extracted from digits (`2e éd.`, `3. Aufl.`, `2nd ed.`), Roman numerals (`IIe éd.`) and spelled-out ordinals in English, French, German, Spanish and Italian (`Second edition`, `Deuxième édition`, `Zweite Auflage`). The number of a printing (`2e tirage`) isn't an edition number, it flags a reprint.
+///
+/// Revised (`rev.`, `corr.`, `überarb.`), augmented (`augm.`, `enl.`, `erw.`) and reprint (`reprint`, `réimpr.`, `Nachdr.`) markers are flagged.
+///
+/// ```
+/// # use b_cleaner::{EditionCleaner, Clean};
+/// # fn main() {
+/// let mut edition = EditionCleaner::from_str("2e éd. rev. et augm.");
+///
+/// edition.clean();
+///
+/// assert_eq!(edition.number(), Some(2));
+/// assert!(edition.revised());
+/// assert!(edition.augmented());
+/// assert!(!edition.reprint());
+///
+/// assert_eq!(EditionCleaner::from_str("Second edition").clean().number(), Some(2));
+/// assert_eq!(EditionCleaner::from_str("3. Aufl.").clean().number(), Some(3));
+/// assert_eq!(EditionCleaner::from_str("New ed.").clean().number(), None);
+/// # }
+/// ```
+pub struct EditionCleaner<'a> {
+    tokens: Vec<Cow<'a, str>>,
+    number: Option<u32>,
+    revised: bool,
+    augmented: bool,
+    reprint: bool
+}
+
+impl <'a>EditionCleaner<'a> {
+    /// Create a new EditionCleaner
+    ///
+    /// ```
+    /// # use b_cleaner::{EditionCleaner, Clean};
+    /// # fn main() {
+    /// let tokens = vec!["Segunda", "edición", "corregida"];
+    /// let mut edition = EditionCleaner::new(&tokens);
+    ///
+    /// edition.clean();
+    ///
+    /// assert_eq!(edition.number(), Some(2));
+    /// assert!(edition.revised());
+    /// # }
+    /// ```
+    pub fn new<R: AsRef<str>>(input: &'a [R]) -> Self {
+        EditionCleaner::with_tokens(input.iter().map(|token| Cow::Borrowed(token.as_ref())))
+    }
+
+    /// Create a new EditionCleaner from a raw string, the string is tokenized with the [Tokenizer](struct.Tokenizer.html)
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(input: &'a str) -> Self {
+        EditionCleaner::with_tokens(Tokenizer::new(input).map(Cow::Borrowed))
+    }
+
+    fn with_tokens<I: Iterator<Item = Cow<'a, str>>>(tokens: I) -> Self {
+        EditionCleaner {
+            tokens: tokens.collect(),
+            number: None,
+            revised: false,
+            augmented: false,
+            reprint: false
+        }
+    }
+
+    /// Get the tokens
+    pub fn tokens(&self) -> &Vec<Cow<'a, str>> {
+        &self.tokens
+    }
+
+    /// Get the number of the edition, None if the statement has no number or before cleaning
+    pub fn number(&self) -> Option<u32> {
+        self.number
+    }
+
+    /// Returns true if the edition is revised or corrected
+    pub fn revised(&self) -> bool {
+        self.revised
+    }
+
+    /// Returns true if the edition is augmented
+    pub fn augmented(&self) -> bool {
+        self.augmented
+    }
+
+    /// Returns true if the statement describes a reprint or a new printing
+    pub fn reprint(&self) -> bool {
+        self.reprint
+    }
+}
+
+/// Parses the number of an edition out of digits, Roman numerals or a spelled-out ordinal
+fn parse_number(word: &Word) -> Option<u32> {
+    parse_ordinal(&word.text, false)
+        .or_else(|| parse_roman_ordinal(word.token).filter(|number| *number <= MAX_ROMAN_EDITION))
+        .or_else(|| SPELLED_ORDINALS.iter().find(|(ordinal, _)| *ordinal == word.text).map(|(_, number)| *number))
+}
+
+impl <'a>Clean for EditionCleaner<'a> {
+    fn clean(&mut self) -> &Self {
+        let words : Vec<Word> = self.tokens.iter()
+            .filter(|token| !token.chars().all(is_punctuation))
+            .map(|token| Word {
+                token: token.trim_end_matches('.'),
+                text: unidecode(&token.to_lowercase()).trim_end_matches('.').to_string()
+            })
+            .collect();
+
+        self.number = None;
+        self.revised = false;
+        self.augmented = false;
+        self.reprint = false;
+
+        for (index, word) in words.iter().enumerate() {
+            let text = word.text.as_str();
+
+            self.revised |= REVISED.contains(&text);
+            self.augmented |= AUGMENTED.contains(&text);
+            self.reprint |= REPRINT.contains(&text);
+
+            if let Some(number) = parse_number(word) {
+                match words.get(index + 1) {
+                    Some(next) if PRINTING.contains(&next.text.as_str()) => self.reprint = true,
+                    _ => self.number = self.number.or(Some(number))
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Edition statements are not stemmed
+    #[cfg(feature = "stem")]
+    fn stem(&mut self, _lang: Algorithm) -> &Self {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edition(input: &str) -> (Option<u32>, bool, bool, bool) {
+        let mut edition = EditionCleaner::from_str(input);
+        edition.clean();
+
+        (edition.number(), edition.revised(), edition.augmented(), edition.reprint())
+    }
+
+    #[test]
+    fn test_edition_statements() {
+        assert_eq!(edition("2nd ed., rev. and enl."), (Some(2), true, true, false));
+        assert_eq!(edition("Zweite, überarbeitete Auflage"), (Some(2), true, false, false));
+        assert_eq!(edition("IIIe édition"), (Some(3), false, false, false));
+        assert_eq!(edition("Nouvelle éd., 2e tirage"), (None, false, false, true));
+        assert_eq!(edition("Reprint of the 1885 ed."), (None, false, false, true));
+        assert_eq!(edition("Terza edizione riveduta"), (Some(3), true, false, false));
+        assert_eq!(edition("DEUXIÈME ÉDITION"), (Some(2), false, false, false));
+        assert_eq!(edition("2nd ed., 3rd printing"), (Some(2), false, false, true));
+        assert_eq!(edition("2. Auflage, 5. Druck"), (Some(2), false, false, true));
+        assert_eq!(edition("NOUVELLE ÉDITION REVUE PAR LE TRADUCTEUR"), (None, true, false, false));
+        assert_eq!(edition("ÉDITION DE LA PLÉIADE"), (None, false, false, false));
+        assert_eq!(edition("VE ÉDITION"), (Some(5), false, false, false));
+    }
+}
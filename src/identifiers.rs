@@ -0,0 +1,618 @@
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+
+use crate::cleaners::Clean;
+use crate::isbn::{Isbn, IsbnError, extract_candidates, mod11_check_digit, numeric_part};
+use crate::tokenizer::{Tokenizer, is_punctuation};
+
+#[cfg(feature = "stem")]
+use rust_stemmers::Algorithm;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Kinds of identifiers recognized by the [IdentifierCleaner](struct.IdentifierCleaner.html)
+pub enum IdentifierKind {
+    /// International Standard Book Number
+    Isbn,
+    /// International Standard Serial Number
+    Issn,
+    /// Digital Object Identifier
+    Doi,
+    /// International Standard Name Identifier
+    Isni,
+    /// Open Researcher and Contributor ID
+    Orcid,
+    /// OCLC control number (WorldCat)
+    Oclc,
+    /// Pica Production Number (SUDOC and IdRef)
+    Ppn
+}
+
+impl IdentifierKind {
+    /// All the kinds of identifiers
+    pub const ALL: [IdentifierKind; 7] = [
+        IdentifierKind::Isbn,
+        IdentifierKind::Issn,
+        IdentifierKind::Doi,
+        IdentifierKind::Isni,
+        IdentifierKind::Orcid,
+        IdentifierKind::Oclc,
+        IdentifierKind::Ppn
+    ];
+
+    /// Labels introducing an identifier (`ISSN 0302-9743`, `(OCoLC)12345678`), compared in lowercase
+    fn labels(&self) -> &'static [&'static str] {
+        match self {
+            IdentifierKind::Isbn => &["isbn"],
+            IdentifierKind::Issn => &["issn"],
+            IdentifierKind::Doi => &["doi"],
+            IdentifierKind::Isni => &["isni"],
+            IdentifierKind::Orcid => &["orcid"],
+            IdentifierKind::Oclc => &["oclc", "ocolc)", "ocm", "ocn"],
+            IdentifierKind::Ppn => &["ppn"]
+        }
+    }
+
+    /// Resolver URLs, the identifier follows the URL
+    fn urls(&self) -> &'static [&'static str] {
+        match self {
+            IdentifierKind::Doi => &["doi.org/"],
+            IdentifierKind::Isni => &["isni.org/isni/", "isni.org/"],
+            IdentifierKind::Orcid => &["orcid.org/"],
+            IdentifierKind::Oclc => &["worldcat.org/oclc/"],
+            IdentifierKind::Ppn => &["sudoc.fr/", "idref.fr/"],
+            _ => &[]
+        }
+    }
+}
+
+impl fmt::Display for IdentifierKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            IdentifierKind::Isbn => "ISBN",
+            IdentifierKind::Issn => "ISSN",
+            IdentifierKind::Doi => "DOI",
+            IdentifierKind::Isni => "ISNI",
+            IdentifierKind::Orcid => "ORCID",
+            IdentifierKind::Oclc => "OCLC number",
+            IdentifierKind::Ppn => "PPN"
+        })
+    }
+}
+
+/// Errors raised while validating an identifier
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdentifierError {
+    /// The identifier doesn't have the expected number of chars
+    InvalidLength {
+        /// The kind of identifier
+        kind: IdentifierKind,
+        /// The number of chars of the identifier, separators excluded
+        length: usize
+    },
+    /// The identifier contains an unexpected char
+    InvalidChar {
+        /// The kind of identifier
+        kind: IdentifierKind,
+        /// The unexpected char
+        found: char
+    },
+    /// The identifier doesn't follow the syntax of its kind (DOI without `10.` prefix, etc.)
+    InvalidFormat(IdentifierKind),
+    /// The check digit doesn't match the other digits
+    InvalidCheckDigit {
+        /// The kind of identifier
+        kind: IdentifierKind,
+        /// The check digit computed from the other digits
+        expected: char,
+        /// The check digit of the identifier
+        found: char
+    },
+    /// The ISBN is invalid
+    Isbn(IsbnError)
+}
+
+impl fmt::Display for IdentifierError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdentifierError::InvalidLength { kind, length } => write!(f, "invalid {} length {}", kind, length),
+            IdentifierError::InvalidChar { kind, found } => write!(f, "invalid char {:?} in {}", found, kind),
+            IdentifierError::InvalidFormat(kind) => write!(f, "invalid {} format", kind),
+            IdentifierError::InvalidCheckDigit { kind, expected, found } => write!(f, "invalid {} check digit {}, expected {}", kind, found, expected),
+            IdentifierError::Isbn(e) => write!(f, "{}", e)
+        }
+    }
+}
+
+impl Error for IdentifierError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            IdentifierError::Isbn(e) => Some(e),
+            _ => None
+        }
+    }
+}
+
+impl From<IsbnError> for IdentifierError {
+    fn from(e: IsbnError) -> Self {
+        IdentifierError::Isbn(e)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// A valid identifier in its canonical form
+///
+/// ```
+/// # use b_cleaner::{Identifier, IdentifierKind};
+/// # fn main() {
+/// let doi = Identifier::parse(IdentifierKind::Doi, "https://doi.org/10.1007/978-3-540-45234-8_1").unwrap();
+/// assert_eq!(doi.as_str(), "10.1007/978-3-540-45234-8_1");
+///
+/// let orcid = Identifier::parse(IdentifierKind::Orcid, "0000000218250097").unwrap();
+/// assert_eq!(orcid, Identifier::Orcid("0000-0002-1825-0097".to_string()));
+/// # }
+/// ```
+pub enum Identifier {
+    /// An ISBN-10 or ISBN-13
+    Isbn(Isbn),
+    /// An ISSN, formatted `0302-9743`
+    Issn(String),
+    /// A DOI in lowercase without resolver URL, formatted `10.1000/xyz123`
+    Doi(String),
+    /// An ISNI, 16 chars without separators
+    Isni(String),
+    /// An ORCID iD, formatted `0000-0002-1825-0097`
+    Orcid(String),
+    /// An OCLC number, without prefix and leading zeros
+    Oclc(String),
+    /// A PPN, 9 chars
+    Ppn(String)
+}
+
+impl Identifier {
+    /// Validate an identifier of a given kind and convert it to its canonical form
+    ///
+    /// Separators (hyphens, white spaces) and resolver URLs are ignored.
+    pub fn parse(kind: IdentifierKind, input: &str) -> Result<Identifier, IdentifierError> {
+        let input = strip_url(kind, input.trim());
+
+        match kind {
+            IdentifierKind::Isbn => Ok(Identifier::Isbn(Isbn::parse(input)?)),
+            IdentifierKind::Issn => {
+                let digits = checked_digits(kind, input, 8)?;
+                check(kind, &digits, mod11_check_digit(&digits[..7]))?;
+
+                Ok(Identifier::Issn(format!("{}-{}", &digits[..4], &digits[4..])))
+            },
+            IdentifierKind::Doi => parse_doi(input).map(Identifier::Doi).ok_or(IdentifierError::InvalidFormat(kind)),
+            IdentifierKind::Isni | IdentifierKind::Orcid => {
+                let digits = checked_digits(kind, input, 16)?;
+                check(kind, &digits, mod11_2_check_digit(&digits[..15]))?;
+
+                match kind {
+                    IdentifierKind::Isni => Ok(Identifier::Isni(digits)),
+                    _ => Ok(Identifier::Orcid(format!("{}-{}-{}-{}", &digits[..4], &digits[4..8], &digits[8..12], &digits[12..])))
+                }
+            },
+            IdentifierKind::Oclc => {
+                if let Some(found) = input.chars().find(|c| !c.is_ascii_digit()) {
+                    return Err(IdentifierError::InvalidChar { kind, found });
+                }
+
+                match input.trim_start_matches('0') {
+                    "" => Err(IdentifierError::InvalidFormat(kind)),
+                    number => Ok(Identifier::Oclc(number.to_string()))
+                }
+            },
+            IdentifierKind::Ppn => {
+                let digits = checked_digits(kind, input, 9)?;
+                check(kind, &digits, mod11_check_digit(&digits[..8]))?;
+
+                Ok(Identifier::Ppn(digits))
+            }
+        }
+    }
+
+    /// Get the kind of the identifier
+    pub fn kind(&self) -> IdentifierKind {
+        match self {
+            Identifier::Isbn(_) => IdentifierKind::Isbn,
+            Identifier::Issn(_) => IdentifierKind::Issn,
+            Identifier::Doi(_) => IdentifierKind::Doi,
+            Identifier::Isni(_) => IdentifierKind::Isni,
+            Identifier::Orcid(_) => IdentifierKind::Orcid,
+            Identifier::Oclc(_) => IdentifierKind::Oclc,
+            Identifier::Ppn(_) => IdentifierKind::Ppn
+        }
+    }
+
+    /// The canonical form of the identifier, ISBNs keep their original format
+    pub fn as_str(&self) -> &str {
+        match self {
+            Identifier::Isbn(isbn) => isbn.as_str(),
+            Identifier::Issn(id) | Identifier::Doi(id) | Identifier::Isni(id) | Identifier::Orcid(id) | Identifier::Oclc(id) | Identifier::Ppn(id) => id
+        }
+    }
+
+    /// Build a key out of the identifier, ISBNs are converted to ISBN-13
+    pub fn key(&self) -> String {
+        match self {
+            Identifier::Isbn(isbn) => isbn.to_isbn13().to_string(),
+            _ => self.as_str().to_string()
+        }
+    }
+}
+
+impl fmt::Display for Identifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Removes the resolver URL in front of an identifier
+fn strip_url(kind: IdentifierKind, input: &str) -> &str {
+    let lowercase = input.to_ascii_lowercase();
+
+    kind.urls().iter()
+        .find_map(|url| lowercase.find(url).map(|position| &input[position + url.len()..]))
+        .unwrap_or(input)
+}
+
+/// Removes separators and checks the length and the chars of an identifier, `X` is only allowed as the check digit
+fn checked_digits(kind: IdentifierKind, input: &str, length: usize) -> Result<String, IdentifierError> {
+    let digits : String = input.chars()
+        .filter(|c| !matches!(c, '-' | ' '))
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+
+    let invalid = digits.chars()
+        .enumerate()
+        .find(|(index, c)| !c.is_ascii_digit() && (*c != 'X' || index + 1 != length));
+
+    if let Some((_, found)) = invalid {
+        return Err(IdentifierError::InvalidChar { kind, found });
+    }
+
+    match digits.len() {
+        len if len == length => Ok(digits),
+        len => Err(IdentifierError::InvalidLength { kind, length: len })
+    }
+}
+
+fn check(kind: IdentifierKind, digits: &str, expected: char) -> Result<(), IdentifierError> {
+    let found = digits.chars().last().expect("digits can't be empty");
+
+    match found == expected {
+        true => Ok(()),
+        false => Err(IdentifierError::InvalidCheckDigit { kind, expected, found })
+    }
+}
+
+/// ISO 7064 MOD 11-2 check digit (ISNI, ORCID)
+fn mod11_2_check_digit(digits: &str) -> char {
+    let total = digits.chars()
+        .filter_map(|c| c.to_digit(10))
+        .fold(0, |total, digit| (total + digit) * 2 % 11);
+
+    match (12 - total) % 11 {
+        10 => 'X',
+        digit => std::char::from_digit(digit, 10).expect("check digit is lower than 10")
+    }
+}
+
+/// A DOI is made of a `10.` prefix followed by a registrant code, a `/` and a suffix, DOIs are case insensitive
+fn parse_doi(input: &str) -> Option<String> {
+    let input = match input.get(..4) {
+        Some(label) if label.eq_ignore_ascii_case("doi:") => input[4..].trim_start(),
+        _ => input
+    };
+    let (prefix, suffix) = input.split_once('/')?;
+
+    let registrant = prefix.strip_prefix("10.")?;
+    let valid_registrant = !registrant.is_empty() && registrant.chars().all(|c| c.is_ascii_digit() || c == '.');
+
+    if !valid_registrant || suffix.is_empty() || suffix.chars().any(char::is_whitespace) {
+        return None;
+    }
+
+    Some(input.to_lowercase())
+}
+
+/// What a token says about identifiers
+enum Detected<'a> {
+    /// The token introduces an identifier (`ISSN`, `doi:`)
+    Label(IdentifierKind),
+    /// The token is an identifier, labelled (`ISSN:0302-9743`) or recognizable by itself (URL, DOI)
+    Candidate(IdentifierKind, Cow<'a, str>)
+}
+
+fn slice<'a>(token: &Cow<'a, str>, start: usize) -> Cow<'a, str> {
+    match token {
+        Cow::Borrowed(token) => Cow::Borrowed(&token[start..]),
+        Cow::Owned(token) => Cow::Owned(token[start..].to_string())
+    }
+}
+
+fn detect<'a>(token: &Cow<'a, str>) -> Option<Detected<'a>> {
+    let lowercase = token.to_ascii_lowercase();
+
+    for kind in IdentifierKind::ALL.iter().copied() {
+        for url in kind.urls() {
+            if let Some(position) = lowercase.find(url) {
+                let start = position + url.len();
+
+                return Some(Detected::Candidate(kind, slice(token, start))).filter(|_| start < token.len());
+            }
+        }
+    }
+
+    let offset = token.len() - token.trim_start_matches('(').len();
+
+    for kind in IdentifierKind::ALL.iter().copied() {
+        for label in kind.labels() {
+            if !lowercase[offset..].starts_with(label) {
+                continue;
+            }
+
+            let start = offset + label.len();
+            let start = start + lowercase[start..].starts_with(':') as usize;
+
+            match lowercase[start..].chars().next() {
+                None => return Some(Detected::Label(kind)),
+                Some(c) if c.is_ascii_digit() => return Some(Detected::Candidate(kind, slice(token, start))),
+                _ => ()
+            }
+        }
+    }
+
+    parse_doi(token).map(|_| Detected::Candidate(IdentifierKind::Doi, token.clone()))
+}
+
+/// Returns true if the token is a range of years (`1900-1908`) rather than an ISSN
+pub(crate) fn is_year_range(token: &str) -> bool {
+    let years : Vec<Option<u32>> = token.split('-').map(|year| year.parse().ok().filter(|year| (1000..2100).contains(year))).collect();
+
+    matches!(years.as_slice(), [Some(start), Some(end)] if start <= end)
+}
+
+/// Recognizes unlabelled ISSN (`0302-9743`) and ORCID (`0000-0002-1825-0097`) out of their shape, ranges of years aren't ISSN and ORCID start with `0000`
+fn shape(token: &str) -> Option<IdentifierKind> {
+    let groups : Vec<usize> = token.split('-').map(str::len).collect();
+
+    match groups.as_slice() {
+        [4, 4] if !is_year_range(token) => Some(IdentifierKind::Issn),
+        [4, 4, 4, 4] if token.starts_with("0000") => Some(IdentifierKind::Orcid),
+        _ => None
+    }
+}
+
+/// Keeps a candidate recognized out of its shape only if it's a valid identifier, so that numbers which merely look like identifiers aren't reported as errors
+fn push_shaped<'a>(candidates: &mut Vec<(IdentifierKind, Cow<'a, str>)>, kind: IdentifierKind, candidate: Cow<'a, str>) {
+    if Identifier::parse(kind, &candidate).is_ok() {
+        candidates.push((kind, candidate));
+    }
+}
+
+/// Extracts identifier candidates, numeric tokens following a label are joined
+///
+/// Unlabelled numeric groups are ISBN candidates, except four groups of four chars starting with `0000` (`0000 0001 2146 438X`) which are ISNI. Unlabelled ranges of years (`1900-1908`) are skipped, other unlabelled ISSN, ISNI and ORCID are only kept when they are valid.
+fn extract_identifiers<'a, I: Iterator<Item = Cow<'a, str>>>(tokens: I) -> Vec<(IdentifierKind, Cow<'a, str>)> {
+    let mut candidates = Vec::new();
+    let mut label : Option<IdentifierKind> = None;
+    let mut group : Vec<Cow<'a, str>> = Vec::new();
+
+    let flush = |candidates: &mut Vec<(IdentifierKind, Cow<'a, str>)>, label: Option<IdentifierKind>, group: &mut Vec<Cow<'a, str>>| {
+        if group.is_empty() {
+            return;
+        }
+
+        let tokens = std::mem::take(group);
+
+        match label {
+            Some(IdentifierKind::Isbn) | None if tokens.len() != 4 || tokens.iter().any(|token| token.len() != 4) || (label.is_none() && tokens[0] != "0000") => {
+                candidates.extend(extract_candidates(tokens.into_iter(), label.is_some()).into_iter().map(|candidate| (IdentifierKind::Isbn, candidate)));
+            },
+            None => push_shaped(candidates, IdentifierKind::Isni, Cow::Owned(tokens.concat())),
+            Some(kind) if tokens.len() == 1 => candidates.extend(tokens.into_iter().map(|token| (kind, token))),
+            Some(kind) => candidates.push((kind, Cow::Owned(tokens.concat())))
+        }
+    };
+
+    for token in tokens {
+        match detect(&token) {
+            Some(Detected::Label(kind)) => {
+                flush(&mut candidates, label, &mut group);
+                label = Some(kind);
+            },
+            Some(Detected::Candidate(kind, candidate)) => {
+                flush(&mut candidates, label.take(), &mut group);
+                candidates.push((kind, candidate));
+            },
+            None => {
+                let punctuation = token.chars().all(is_punctuation);
+
+                match numeric_part(token) {
+                    Some(numeric) if label.is_none() && is_year_range(&numeric) => flush(&mut candidates, None, &mut group),
+                    Some(numeric) => match shape(&numeric).filter(|_| label.is_none()) {
+                        Some(kind) => {
+                            flush(&mut candidates, None, &mut group);
+                            push_shaped(&mut candidates, kind, numeric);
+                        },
+                        None => group.push(numeric)
+                    },
+                    None if punctuation && group.is_empty() => (),
+                    None => flush(&mut candidates, label.take(), &mut group)
+                }
+            }
+        }
+    }
+
+    flush(&mut candidates, label, &mut group);
+    candidates
+}
+
+#[derive(Debug, Clone)]
+/// A struct dedicated to identifiers extraction and validation (ISBN, ISSN, DOI, ISNI, ORCID, OCLC number, PPN)
+///
+/// Identifiers are recognized out of their label (`ISSN 0302-9743`, `(OCoLC)12345678`, `PPN 123456789`), their resolver URL (`https://orcid.org/0000-0002-1825-0097`) or their shape (`10.1000/xyz123`, `0302-9743`), unlabelled ISSN, ISNI and ORCID are only kept when they are valid. Unlabelled numbers are ISBN candidates, see the [IsbnCleaner](struct.IsbnCleaner.html).
+///
+/// Candidates are validated while cleaning, check digits are verified for all the identifiers but DOI and OCLC numbers.
+///
+/// ```
+/// # use b_cleaner::{IdentifierCleaner, Identifier, IdentifierKind, Clean};
+/// # fn main() {
+/// let mut identifiers = IdentifierCleaner::from_str("ISSN 0302-9743 ; doi:10.1007/BFb0026477. (OCoLC)00012345 ; ISNI 0000 0001 2103 2683");
+///
+/// identifiers.clean();
+///
+/// assert_eq!(identifiers.identifiers(), &vec![
+///     Identifier::Issn("0302-9743".to_string()),
+///     Identifier::Doi("10.1007/bfb0026477".to_string()),
+///     Identifier::Oclc("12345".to_string()),
+///     Identifier::Isni("0000000121032683".to_string())
+/// ]);
+/// assert_eq!(identifiers.keys(IdentifierKind::Issn), vec!["0302-9743"]);
+/// # }
+/// ```
+pub struct IdentifierCleaner<'a> {
+    candidates: Vec<(IdentifierKind, Cow<'a, str>)>,
+    identifiers: Vec<Identifier>,
+    errors: Vec<(Cow<'a, str>, IdentifierError)>
+}
+
+impl <'a>IdentifierCleaner<'a> {
+    /// Create a new IdentifierCleaner
+    ///
+    /// ```
+    /// # use b_cleaner::{IdentifierCleaner, IdentifierKind};
+    /// # fn main() {
+    /// let tokens = vec!["PPN", "123456789", "ISBN", "2-07-036822-X"];
+    /// let identifiers = IdentifierCleaner::new(&tokens);
+    ///
+    /// assert_eq!(identifiers.candidates()[0], (IdentifierKind::Ppn, "123456789".into()));
+    /// assert_eq!(identifiers.candidates()[1], (IdentifierKind::Isbn, "2-07-036822-X".into()));
+    /// # }
+    /// ```
+    pub fn new<R: AsRef<str>>(input: &'a [R]) -> Self {
+        IdentifierCleaner::with_tokens(input.iter().map(|token| Cow::Borrowed(token.as_ref())))
+    }
+
+    /// Create a new IdentifierCleaner from a raw string, the string is tokenized with the [Tokenizer](struct.Tokenizer.html)
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(input: &'a str) -> Self {
+        IdentifierCleaner::with_tokens(Tokenizer::new(input).map(Cow::Borrowed))
+    }
+
+    fn with_tokens<I: Iterator<Item = Cow<'a, str>>>(tokens: I) -> Self {
+        IdentifierCleaner {
+            candidates: extract_identifiers(tokens),
+            identifiers: Vec::new(),
+            errors: Vec::new()
+        }
+    }
+
+    /// Get the identifier candidates extracted from the input
+    pub fn candidates(&self) -> &Vec<(IdentifierKind, Cow<'a, str>)> {
+        &self.candidates
+    }
+
+    /// Get the valid identifiers, in their canonical form
+    pub fn identifiers(&self) -> &Vec<Identifier> {
+        &self.identifiers
+    }
+
+    /// Get the invalid candidates and their error
+    pub fn errors(&self) -> &Vec<(Cow<'a, str>, IdentifierError)> {
+        &self.errors
+    }
+
+    /// Build a key for each valid identifier of a kind, see [Identifier::key](enum.Identifier.html#method.key)
+    pub fn keys(&self, kind: IdentifierKind) -> Vec<String> {
+        let mut keys : Vec<String> = Vec::new();
+
+        for key in self.identifiers.iter().filter(|identifier| identifier.kind() == kind).map(Identifier::key) {
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+
+        keys
+    }
+}
+
+impl <'a>Clean for IdentifierCleaner<'a> {
+    fn clean(&mut self) -> &Self {
+        self.identifiers.clear();
+        self.errors.clear();
+
+        for (kind, candidate) in &self.candidates {
+            match Identifier::parse(*kind, candidate) {
+                Ok(identifier) => self.identifiers.push(identifier),
+                Err(error) => self.errors.push((candidate.clone(), error))
+            }
+        }
+
+        self
+    }
+
+    /// Identifiers are not stemmed
+    #[cfg(feature = "stem")]
+    fn stem(&mut self, _lang: Algorithm) -> &Self {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_digits() {
+        assert_eq!(Identifier::parse(IdentifierKind::Issn, "2049-3630"), Ok(Identifier::Issn("2049-3630".to_string())));
+        assert_eq!(Identifier::parse(IdentifierKind::Issn, "0302-9742"), Err(IdentifierError::InvalidCheckDigit { kind: IdentifierKind::Issn, expected: '3', found: '2' }));
+        assert_eq!(Identifier::parse(IdentifierKind::Isni, "0000 0001 2103 2683"), Ok(Identifier::Isni("0000000121032683".to_string())));
+        assert_eq!(Identifier::parse(IdentifierKind::Orcid, "0000-0002-1694-233X").map(|orcid| orcid.key()), Ok("0000-0002-1694-233X".to_string()));
+        assert_eq!(Identifier::parse(IdentifierKind::Ppn, "12345678X"), Err(IdentifierError::InvalidCheckDigit { kind: IdentifierKind::Ppn, expected: '9', found: 'X' }));
+        assert_eq!(Identifier::parse(IdentifierKind::Ppn, "1234"), Err(IdentifierError::InvalidLength { kind: IdentifierKind::Ppn, length: 4 }));
+        assert_eq!(Identifier::parse(IdentifierKind::Oclc, "ocm123"), Err(IdentifierError::InvalidChar { kind: IdentifierKind::Oclc, found: 'o' }));
+        assert_eq!(Identifier::parse(IdentifierKind::Doi, "11.1000/xyz"), Err(IdentifierError::InvalidFormat(IdentifierKind::Doi)));
+    }
+
+    #[test]
+    fn test_extract_identifiers() {
+        let identifiers = IdentifierCleaner::from_str("ORCID: https://orcid.org/0000-0002-1825-0097. ocn123456789 ; 2049-3630 ; ISBN 978-2-07-036822-8 (br.) 12 EUR");
+        let candidates : Vec<(IdentifierKind, &str)> = identifiers.candidates().iter().map(|(kind, candidate)| (*kind, candidate.as_ref())).collect();
+
+        assert_eq!(candidates, vec![
+            (IdentifierKind::Orcid, "0000-0002-1825-0097"),
+            (IdentifierKind::Oclc, "123456789"),
+            (IdentifierKind::Issn, "2049-3630"),
+            (IdentifierKind::Isbn, "978-2-07-036822-8")
+        ]);
+
+        let identifiers = IdentifierCleaner::from_str("Rapport annuel 1900-1908 ; ISSN 1900-1908");
+        let candidates : Vec<(IdentifierKind, &str)> = identifiers.candidates().iter().map(|(kind, candidate)| (*kind, candidate.as_ref())).collect();
+
+        assert_eq!(candidates, vec![(IdentifierKind::Issn, "1900-1908")]);
+        assert!(is_year_range("1900-1908") && !is_year_range("1908-1900") && !is_year_range("0302-9743"));
+
+        let mut identifiers = IdentifierCleaner::from_str("Rapport annuel 1900-1908");
+        identifiers.clean();
+
+        assert!(identifiers.identifiers().is_empty());
+        assert!(identifiers.errors().is_empty());
+
+        for input in ["Wars of 1914 1918 1939 1945", "Tel 0145 6789 1234 5678", "0000 0001 2103 2684", "0000-0002-1825-0098 ; 0302-9742"] {
+            let mut identifiers = IdentifierCleaner::from_str(input);
+            identifiers.clean();
+
+            assert!(identifiers.candidates().is_empty(), "{}", input);
+            assert!(identifiers.errors().is_empty(), "{}", input);
+        }
+
+        let mut identifiers = IdentifierCleaner::from_str("ISNI 0000 0001 2103 2684");
+        identifiers.clean();
+
+        assert_eq!(identifiers.errors().len(), 1);
+    }
+}
@@ -0,0 +1,228 @@
+use std::borrow::Cow;
+
+use crate::author::split_off_role_phrase;
+use crate::cleaners::{AuthorCleaner, Clean};
+use crate::pipeline::*;
+use crate::tokenizer::Tokenizer;
+
+#[cfg(feature = "stem")]
+use rust_stemmers::Algorithm;
+
+/// ISBD prescribed punctuation of the title and statement of responsibility area
+const PARALLEL_TITLE: &str = "=";
+const OTHER_TITLE_INFORMATION: &str = ":";
+const STATEMENT_OF_RESPONSIBILITY: &str = "/";
+const SUBSEQUENT_STATEMENT: &str = ";";
+
+#[derive(Debug, Clone)]
+/// A struct dedicated to ISBD title statements cleaning (MARC 245 / UNIMARC 200)
+///
+/// A title statement such as `Title = Parallel title : other title information / statement of responsibility ; second statement` is split into :
+/// * the title proper
+/// * the parallel titles, introduced by `=`
+/// * the other title information, introduced by `:`
+/// * the statements of responsibility, introduced by `/` and separated by `;`
+///
+/// ISBD punctuation marks must be separated tokens, this is the case when the input is tokenized with [from_str](#method.from_str).
+///
+/// Titles are cleaned by the [title pipeline](struct.Pipeline.html#method.title) and statements of responsibility by the [AuthorCleaner](struct.AuthorCleaner.html) once the role phrase heading them (`par`, `illustrated by`, `trad. de l'anglais par`) is removed, so statements and authors have the same key.
+///
+/// ```
+/// # use b_cleaner::{TitleStatementCleaner, Clean};
+/// # fn main() {
+/// let mut statement = TitleStatementCleaner::from_str("Le petit prince = The little prince : roman / Antoine de Saint-Exupéry ; trad. de l'anglais par Jean Dupont");
+///
+/// statement.clean();
+///
+/// assert_eq!(statement.title_proper(), &vec!["petit", "prince"]);
+/// assert_eq!(statement.parallel_titles(), &vec![vec!["little", "prince"]]);
+/// assert_eq!(statement.other_title_information(), &vec![vec!["roman"]]);
+/// assert_eq!(statement.statements_of_responsibility(), &vec![vec!["antoine", "de", "saint-exupery"], vec!["jean", "dupont"]]);
+/// # }
+/// ```
+pub struct TitleStatementCleaner<'a> {
+    title_proper: Vec<Cow<'a, str>>,
+    parallel_titles: Vec<Vec<Cow<'a, str>>>,
+    other_title_information: Vec<Vec<Cow<'a, str>>>,
+    statements_of_responsibility: Vec<Vec<Cow<'a, str>>>,
+    token_min_lenght: usize
+}
+
+impl <'a>TitleStatementCleaner<'a> {
+    /// Create a new TitleStatementCleaner
+    ///
+    /// ```
+    /// # use b_cleaner::TitleStatementCleaner;
+    /// # fn main() {
+    /// let tokens = vec!["Lorem", "ipsum", ":", "dolor", "/", "John", "Doe"];
+    /// let statement = TitleStatementCleaner::new(&tokens);
+    ///
+    /// assert_eq!(statement.title_proper(), &vec!["Lorem", "ipsum"]);
+    /// # }
+    /// ```
+    pub fn new<R: AsRef<str>>(input: &'a [R]) -> Self {
+        TitleStatementCleaner::with_tokens(input.iter().map(|token| Cow::Borrowed(token.as_ref())))
+    }
+
+    /// Create a new TitleStatementCleaner from a raw string, the string is tokenized with the [Tokenizer](struct.Tokenizer.html)
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(input: &'a str) -> Self {
+        TitleStatementCleaner::with_tokens(Tokenizer::new(input).map(Cow::Borrowed))
+    }
+
+    fn with_tokens<I: Iterator<Item = Cow<'a, str>>>(tokens: I) -> Self {
+        let mut statement = TitleStatementCleaner {
+            title_proper: Vec::new(),
+            parallel_titles: Vec::new(),
+            other_title_information: Vec::new(),
+            statements_of_responsibility: Vec::new(),
+            token_min_lenght: 3
+        };
+
+        let mut current = Part::TitleProper;
+
+        for token in tokens {
+            match (current, token.as_ref()) {
+                (Part::StatementOfResponsibility, SUBSEQUENT_STATEMENT) | (Part::StatementOfResponsibility, PARALLEL_TITLE) => {
+                    statement.statements_of_responsibility.push(Vec::new());
+                },
+                (Part::StatementOfResponsibility, _) => last(&mut statement.statements_of_responsibility).push(token),
+                (_, STATEMENT_OF_RESPONSIBILITY) => {
+                    statement.statements_of_responsibility.push(Vec::new());
+                    current = Part::StatementOfResponsibility;
+                },
+                (_, PARALLEL_TITLE) => {
+                    statement.parallel_titles.push(Vec::new());
+                    current = Part::ParallelTitle;
+                },
+                (_, OTHER_TITLE_INFORMATION) => {
+                    statement.other_title_information.push(Vec::new());
+                    current = Part::OtherTitleInformation;
+                },
+                (Part::TitleProper, _) => statement.title_proper.push(token),
+                (Part::ParallelTitle, _) => last(&mut statement.parallel_titles).push(token),
+                (Part::OtherTitleInformation, _) => last(&mut statement.other_title_information).push(token)
+            }
+        }
+
+        statement
+    }
+
+    /// Get the title proper
+    pub fn title_proper(&self) -> &Vec<Cow<'a, str>> {
+        &self.title_proper
+    }
+
+    /// Get the parallel titles
+    pub fn parallel_titles(&self) -> &Vec<Vec<Cow<'a, str>>> {
+        &self.parallel_titles
+    }
+
+    /// Get the other title information
+    pub fn other_title_information(&self) -> &Vec<Vec<Cow<'a, str>>> {
+        &self.other_title_information
+    }
+
+    /// Get the statements of responsibility
+    pub fn statements_of_responsibility(&self) -> &Vec<Vec<Cow<'a, str>>> {
+        &self.statements_of_responsibility
+    }
+
+    /// Set the token min length treshold (inclusive) of titles. Tokens under this treshold will be filtered out
+    pub fn token_min_lenght(&mut self, thresold: usize) -> &Self {
+        self.token_min_lenght = thresold;
+        self
+    }
+
+    fn titles_mut(&mut self) -> impl Iterator<Item = &mut Vec<Cow<'a, str>>> {
+        std::iter::once(&mut self.title_proper)
+            .chain(self.parallel_titles.iter_mut())
+            .chain(self.other_title_information.iter_mut())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Part {
+    TitleProper,
+    ParallelTitle,
+    OtherTitleInformation,
+    StatementOfResponsibility
+}
+
+fn last<'a, 'b>(parts: &'b mut Vec<Vec<Cow<'a, str>>>) -> &'b mut Vec<Cow<'a, str>> {
+    if parts.is_empty() {
+        parts.push(Vec::new());
+    }
+
+    parts.last_mut().expect("parts can't be empty")
+}
+
+impl <'a>Clean for TitleStatementCleaner<'a> {
+    fn clean(&mut self) -> &Self {
+        let title_pipeline = Pipeline::preset(Preset::Title(self.token_min_lenght, NumericPolicy::Drop, None), || Pipeline::title(self.token_min_lenght));
+
+        self.titles_mut().for_each(|title| title_pipeline.apply_skipping(title, "split_at_strong_punctuation"));
+        self.statements_of_responsibility = std::mem::take(&mut self.statements_of_responsibility).into_iter()
+            .map(|statement| {
+                let mut author = AuthorCleaner::with_tokens(split_off_role_phrase(statement).into_iter());
+                author.clean();
+                author.into_tokens()
+            })
+            .collect();
+
+        self.parallel_titles.retain(|title| !title.is_empty());
+        self.other_title_information.retain(|information| !information.is_empty());
+        self.statements_of_responsibility.retain(|statement| !statement.is_empty());
+
+        self
+    }
+
+    #[cfg(feature = "stem")]
+    fn stem(&mut self, lang: Algorithm) -> &Self {
+        let stem = Stem::new(lang);
+
+        self.titles_mut().for_each(|title| stem.apply(title));
+        self.statements_of_responsibility.iter_mut().for_each(|statement| stem.apply(statement));
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_title_statement_without_responsibility() {
+        let mut statement = TitleStatementCleaner::from_str("Lorem ipsum dolor : consectetur adipiscing : elit sed");
+        statement.clean();
+
+        assert_eq!(statement.title_proper(), &vec!["lorem", "ipsum", "dolor"]);
+        assert_eq!(statement.other_title_information(), &vec![vec!["consectetur", "adipiscing"], vec!["elit"]]);
+        assert!(statement.parallel_titles().is_empty());
+        assert!(statement.statements_of_responsibility().is_empty());
+    }
+
+    #[test]
+    fn test_colon_and_equal_inside_responsibility() {
+        let statement = TitleStatementCleaner::from_str("Lorem / John Doe : ed. = Jane Roe");
+
+        assert_eq!(statement.statements_of_responsibility(), &vec![vec!["John", "Doe", ":", "ed", "."], vec!["Jane", "Roe"]]);
+    }
+
+    #[test]
+    fn test_statements_cleaned_as_authors() {
+        let mut statement = TitleStatementCleaner::from_str("Lorem ipsum / John Doe (1950-2020), ed. ; trad. de Jane Roe");
+        statement.clean();
+
+        assert_eq!(statement.statements_of_responsibility(), &vec![vec!["john", "doe"], vec!["jane", "roe"]]);
+    }
+
+    #[test]
+    fn test_role_phrases_removed() {
+        let mut statement = TitleStatementCleaner::from_str("Lorem ipsum / par Jean Dupont ; trad. de l'anglais par Marie Roe ; illustrated by Jane Doe");
+        statement.clean();
+
+        assert_eq!(statement.statements_of_responsibility(), &vec![vec!["jean", "dupont"], vec!["marie", "roe"], vec!["jane", "doe"]]);
+    }
+}
@@ -0,0 +1,410 @@
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::cleaners::Clean;
+use crate::identifiers::is_year_range;
+use crate::tokenizer::Tokenizer;
+
+#[cfg(feature = "stem")]
+use rust_stemmers::Algorithm;
+
+/// Numeric groups with less digits are not considered as ISBN candidates (prices, years, pages)
+const MIN_CANDIDATE_DIGITS: usize = 8;
+
+/// Errors raised while validating an ISBN
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IsbnError {
+    /// An ISBN is made of 10 or 13 digits
+    InvalidLength(usize),
+    /// The ISBN contains a char which is neither a digit nor a separator, `X` is only allowed as the check digit of an ISBN-10
+    InvalidChar(char),
+    /// ISBN-13 start with `978` or `979`
+    InvalidPrefix(String),
+    /// The check digit doesn't match the other digits
+    InvalidCheckDigit {
+        /// The check digit computed from the other digits
+        expected: char,
+        /// The check digit of the ISBN
+        found: char
+    },
+    /// ISBN-13 starting with `979` have no ISBN-10 equivalent
+    NoIsbn10
+}
+
+impl fmt::Display for IsbnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IsbnError::InvalidLength(len) => write!(f, "invalid ISBN length {}, expected 10 or 13 digits", len),
+            IsbnError::InvalidChar(c) => write!(f, "invalid char {:?} in ISBN", c),
+            IsbnError::InvalidPrefix(prefix) => write!(f, "invalid ISBN-13 prefix {}, expected 978 or 979", prefix),
+            IsbnError::InvalidCheckDigit { expected, found } => write!(f, "invalid ISBN check digit {}, expected {}", found, expected),
+            IsbnError::NoIsbn10 => write!(f, "ISBN-13 starting with 979 can't be converted to ISBN-10")
+        }
+    }
+}
+
+impl Error for IsbnError {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// A valid ISBN-10 or ISBN-13, stored without separators
+///
+/// ```
+/// # use b_cleaner::Isbn;
+/// # fn main() {
+/// let isbn : Isbn = "2-07-036822-X".parse().unwrap();
+///
+/// assert_eq!(isbn.as_str(), "207036822X");
+/// assert_eq!(isbn.to_isbn13().as_str(), "9782070368228");
+/// assert_eq!(isbn.to_isbn13().to_isbn10(), Ok(isbn));
+/// # }
+/// ```
+pub struct Isbn {
+    digits: String
+}
+
+impl Isbn {
+    /// Parse and validate an ISBN, hyphens and white spaces are ignored
+    pub fn parse(input: &str) -> Result<Isbn, IsbnError> {
+        let digits : String = input.chars()
+            .filter(|c| !matches!(c, '-' | ' ' | '\u{2010}' | '\u{2011}'))
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+
+        if let Some(c) = digits.chars().find(|c| !c.is_ascii_digit() && *c != 'X') {
+            return Err(IsbnError::InvalidChar(c));
+        }
+
+        let len = digits.chars().count();
+
+        if len != 10 && len != 13 {
+            return Err(IsbnError::InvalidLength(len));
+        }
+
+        if digits[..len - 1].contains('X') || len == 13 && digits.ends_with('X') {
+            return Err(IsbnError::InvalidChar('X'));
+        }
+
+        if len == 13 && !digits.starts_with("978") && !digits.starts_with("979") {
+            return Err(IsbnError::InvalidPrefix(digits[..3].to_string()));
+        }
+
+        let expected = match len {
+            10 => mod11_check_digit(&digits[..9]),
+            _ => isbn13_check_digit(&digits[..12])
+        };
+        let found = digits.chars().last().expect("digits can't be empty");
+
+        if expected != found {
+            return Err(IsbnError::InvalidCheckDigit { expected, found });
+        }
+
+        Ok(Isbn {
+            digits
+        })
+    }
+
+    /// The ISBN without separators
+    pub fn as_str(&self) -> &str {
+        &self.digits
+    }
+
+    /// Returns true if the ISBN is an ISBN-13
+    pub fn is_isbn13(&self) -> bool {
+        self.digits.len() == 13
+    }
+
+    /// Convert to ISBN-13, ISBN-13 are returned as is
+    pub fn to_isbn13(&self) -> Isbn {
+        if self.is_isbn13() {
+            return self.clone();
+        }
+
+        let mut digits = format!("978{}", &self.digits[..9]);
+        digits.push(isbn13_check_digit(&digits));
+
+        Isbn {
+            digits
+        }
+    }
+
+    /// Convert to ISBN-10, only ISBN-13 starting with `978` can be converted
+    pub fn to_isbn10(&self) -> Result<Isbn, IsbnError> {
+        if !self.is_isbn13() {
+            return Ok(self.clone());
+        }
+
+        if !self.digits.starts_with("978") {
+            return Err(IsbnError::NoIsbn10);
+        }
+
+        let mut digits = self.digits[3..12].to_string();
+        digits.push(mod11_check_digit(&digits));
+
+        Ok(Isbn {
+            digits
+        })
+    }
+}
+
+impl FromStr for Isbn {
+    type Err = IsbnError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Isbn::parse(input)
+    }
+}
+
+impl fmt::Display for Isbn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.digits)
+    }
+}
+
+/// Modulo 11 check digit, digits are weighted from their count + 1 down to 2 (ISBN-10, ISSN, PPN)
+pub(crate) fn mod11_check_digit(digits: &str) -> char {
+    let weight = digits.chars().count() as u32 + 1;
+    let sum : u32 = digits.chars()
+        .filter_map(|c| c.to_digit(10))
+        .enumerate()
+        .map(|(index, digit)| (weight - index as u32) * digit)
+        .sum();
+
+    match (11 - sum % 11) % 11 {
+        10 => 'X',
+        digit => std::char::from_digit(digit, 10).expect("check digit is lower than 10")
+    }
+}
+
+/// Check digit of the first 12 digits of an ISBN-13
+fn isbn13_check_digit(digits: &str) -> char {
+    let sum : u32 = digits.chars()
+        .filter_map(|c| c.to_digit(10))
+        .enumerate()
+        .map(|(index, digit)| if index % 2 == 0 { digit } else { 3 * digit })
+        .sum();
+
+    std::char::from_digit((10 - sum % 10) % 10, 10).expect("check digit is lower than 10")
+}
+
+#[derive(Debug, Clone)]
+/// A struct dedicated to ISBN extraction and validation
+///
+/// ISBN candidates are extracted from noisy strings (`ISBN 2-07-036822-X (br.)`), a candidate is a group of numeric tokens with at least 8 digits following an `ISBN` label, unlabelled groups are only candidates if they have the shape of an ISBN (`2-07-036822-8`, `207036822X`) or are valid ISBNs. Candidates are validated while cleaning, invalid candidates are reported along with their error.
+///
+/// ```
+/// # use b_cleaner::{IsbnCleaner, IsbnError, Clean};
+/// # fn main() {
+/// let mut isbn_cleaner = IsbnCleaner::from_str("ISBN 2-07-036822-X (br.) ; ISBN 978-2-07-036823-4 : 12 EUR");
+///
+/// isbn_cleaner.clean();
+///
+/// assert_eq!(isbn_cleaner.isbns()[0].as_str(), "207036822X");
+/// assert_eq!(isbn_cleaner.keys(), vec!["9782070368228"]);
+/// assert_eq!(isbn_cleaner.errors()[0].1, IsbnError::InvalidCheckDigit { expected: '5', found: '4' });
+/// # }
+/// ```
+pub struct IsbnCleaner<'a> {
+    candidates: Vec<Cow<'a, str>>,
+    isbns: Vec<Isbn>,
+    errors: Vec<(Cow<'a, str>, IsbnError)>
+}
+
+impl <'a>IsbnCleaner<'a> {
+    /// Create a new IsbnCleaner
+    ///
+    /// ```
+    /// # use b_cleaner::IsbnCleaner;
+    /// # fn main() {
+    /// let tokens = vec!["ISBN", "978", "2", "07", "036822", "8", "(br.)"];
+    /// let isbn_cleaner = IsbnCleaner::new(&tokens);
+    ///
+    /// assert_eq!(isbn_cleaner.candidates(), &vec!["9782070368228"]);
+    /// # }
+    /// ```
+    pub fn new<R: AsRef<str>>(input: &'a [R]) -> Self {
+        IsbnCleaner::with_tokens(input.iter().map(|token| Cow::Borrowed(token.as_ref())))
+    }
+
+    /// Create a new IsbnCleaner from a raw string, the string is tokenized with the [Tokenizer](struct.Tokenizer.html)
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(input: &'a str) -> Self {
+        IsbnCleaner::with_tokens(Tokenizer::new(input).map(Cow::Borrowed))
+    }
+
+    fn with_tokens<I: Iterator<Item = Cow<'a, str>>>(tokens: I) -> Self {
+        IsbnCleaner {
+            candidates: extract_candidates(tokens, false),
+            isbns: Vec::new(),
+            errors: Vec::new()
+        }
+    }
+
+    /// Get the ISBN candidates extracted from the input
+    pub fn candidates(&self) -> &Vec<Cow<'a, str>> {
+        &self.candidates
+    }
+
+    /// Get the valid ISBNs, in their original format
+    pub fn isbns(&self) -> &Vec<Isbn> {
+        &self.isbns
+    }
+
+    /// Get the invalid candidates and their error
+    pub fn errors(&self) -> &Vec<(Cow<'a, str>, IsbnError)> {
+        &self.errors
+    }
+
+    /// Build a key for each valid ISBN, keys are ISBN-13 so both formats of an ISBN have the same key
+    pub fn keys(&self) -> Vec<String> {
+        let mut keys : Vec<String> = Vec::with_capacity(self.isbns.len());
+
+        for key in self.isbns.iter().map(|isbn| isbn.to_isbn13().to_string()) {
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+
+        keys
+    }
+}
+
+/// Extracts groups of numeric tokens, a token prefixed by letters (`ISBN:2070368228`) is numeric
+///
+/// Groups following an `ISBN` label, or all the groups when `labelled` is true, are candidates as soon as they have enough digits. Unlabelled groups are only candidates if they have the shape of an ISBN or are valid ISBNs, so years, ranges of years (`1999-2001`) and phone numbers are skipped.
+pub(crate) fn extract_candidates<'a, I: Iterator<Item = Cow<'a, str>>>(tokens: I, labelled: bool) -> Vec<Cow<'a, str>> {
+    let mut candidates = Vec::new();
+    let mut group : Vec<Cow<'a, str>> = Vec::new();
+    let mut label = labelled;
+
+    let mut flush = |group: &mut Vec<Cow<'a, str>>, labelled: bool| {
+        let digits = group.iter().flat_map(|token| token.chars()).filter(char::is_ascii_digit).count();
+
+        if digits >= MIN_CANDIDATE_DIGITS {
+            let candidate = match group.len() {
+                1 => group.pop().expect("group has one token"),
+                _ => Cow::Owned(group.concat())
+            };
+
+            if labelled || has_isbn_shape(&candidate) || Isbn::parse(&candidate).is_ok() {
+                candidates.push(candidate);
+            }
+        }
+
+        group.clear();
+    };
+
+    for token in tokens {
+        let is_label = token.to_lowercase().starts_with("isbn");
+        let numeric = numeric_part(token);
+
+        match numeric {
+            Some(numeric) if !label && !is_label && is_year_range(&numeric) => flush(&mut group, label),
+            Some(numeric) if numeric.chars().filter(char::is_ascii_digit).count() >= MIN_CANDIDATE_DIGITS => {
+                flush(&mut group, label);
+                group.push(numeric);
+                flush(&mut group, label || is_label);
+                label = labelled;
+            },
+            Some(numeric) => group.push(numeric),
+            None => {
+                flush(&mut group, label);
+                label = labelled || is_label;
+            }
+        }
+    }
+
+    flush(&mut group, label);
+    candidates
+}
+
+/// Returns true if a candidate has the shape of an ISBN: 10 or 13 digits split into groups by hyphens (`2-07-036822-8`) or ending with the `X` check digit
+fn has_isbn_shape(candidate: &str) -> bool {
+    let len = candidate.chars().filter(|c| *c != '-').count();
+    let groups : Vec<&str> = candidate.split('-').collect();
+
+    let hyphenated = groups.len() >= 4 && groups.iter().all(|group| !group.is_empty());
+
+    (len == 10 || len == 13) && (hyphenated || candidate.ends_with(['X', 'x']))
+}
+
+/// The numeric part of a token (digits, hyphens and the `X` check digit), leading letters and punctuation are skipped
+pub(crate) fn numeric_part(token: Cow<str>) -> Option<Cow<str>> {
+    if matches!(token.as_ref(), "X" | "x") {
+        return Some(token);
+    }
+
+    let start = token.find(|c: char| c.is_ascii_digit())?;
+    let end = token.rfind(|c: char| c.is_ascii_digit() || c == 'X' || c == 'x').map(|index| index + 1)?;
+    let numeric = &token[start..end];
+
+    if !numeric.chars().all(|c| c.is_ascii_digit() || matches!(c, '-' | 'X' | 'x')) {
+        return None;
+    }
+
+    Some(match token {
+        Cow::Borrowed(token) => Cow::Borrowed(&token[start..end]),
+        Cow::Owned(token) => Cow::Owned(token[start..end].to_string())
+    })
+}
+
+impl <'a>Clean for IsbnCleaner<'a> {
+    fn clean(&mut self) -> &Self {
+        self.isbns.clear();
+        self.errors.clear();
+
+        for candidate in &self.candidates {
+            match Isbn::parse(candidate) {
+                Ok(isbn) => self.isbns.push(isbn),
+                Err(error) => self.errors.push((candidate.clone(), error))
+            }
+        }
+
+        self
+    }
+
+    /// Identifiers are not stemmed
+    #[cfg(feature = "stem")]
+    fn stem(&mut self, _lang: Algorithm) -> &Self {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_digits() {
+        assert_eq!(Isbn::parse("0-306-40615-2").map(|isbn| isbn.to_isbn13().to_string()), Ok("9780306406157".to_string()));
+        assert_eq!(Isbn::parse("979-10-90636-07-1").map(|isbn| isbn.to_isbn10()), Ok(Err(IsbnError::NoIsbn10)));
+        assert_eq!(Isbn::parse("0-306-40615-3"), Err(IsbnError::InvalidCheckDigit { expected: '2', found: '3' }));
+        assert_eq!(Isbn::parse("123-4-56789-012-3"), Err(IsbnError::InvalidPrefix("123".to_string())));
+        assert_eq!(Isbn::parse("20703682"), Err(IsbnError::InvalidLength(8)));
+        assert_eq!(Isbn::parse("2X7036822X"), Err(IsbnError::InvalidChar('X')));
+    }
+
+    #[test]
+    fn test_extract_candidates() {
+        let isbn_cleaner = IsbnCleaner::from_str("ISBN:2070368228. - Paris, 1999, 318 p. ; ISBN 2 07 036822 X");
+
+        assert_eq!(isbn_cleaner.candidates(), &vec!["2070368228", "207036822X"]);
+    }
+
+    #[test]
+    fn test_unlabelled_noise() {
+        for input in ["Paris : Gallimard, 1999-2001", "Tel. 01 45 67 89 12", "Paris : Gallimard, 1999, 20703682"] {
+            let mut isbn_cleaner = IsbnCleaner::from_str(input);
+            isbn_cleaner.clean();
+
+            assert!(isbn_cleaner.candidates().is_empty(), "{}", input);
+        }
+
+        let mut isbn_cleaner = IsbnCleaner::from_str("2-07-036822-9 ; 978-2-07-036822-8 ; 9782070368228");
+        isbn_cleaner.clean();
+
+        assert_eq!(isbn_cleaner.candidates(), &vec!["2-07-036822-9", "978-2-07-036822-8", "9782070368228"]);
+        assert_eq!(isbn_cleaner.errors()[0].1, IsbnError::InvalidCheckDigit { expected: 'X', found: '9' });
+    }
+}
@@ -0,0 +1,97 @@
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "config")]
+use serde::{Deserialize, Serialize};
+
+/// Languages supported by the language dependent steps
+///
+/// A language can be parsed from its english name or from its ISO 639-1 and ISO 639-2 codes. Dutch, Portuguese, Arabic and the Scandinavian languages only provide nobiliary particles and conjunctions, they have no bundled stopword list.
+///
+/// ```
+/// # use b_cleaner::Language;
+/// # fn main() {
+/// assert_eq!("fr".parse::<Language>(), Ok(Language::French));
+/// assert_eq!("ger".parse::<Language>(), Ok(Language::German));
+/// assert_eq!("English".parse::<Language>(), Ok(Language::English));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "config", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "config", serde(rename_all = "lowercase"))]
+pub enum Language {
+    /// French
+    #[cfg_attr(feature = "config", serde(alias = "fr", alias = "fre", alias = "fra"))]
+    French,
+    /// English
+    #[cfg_attr(feature = "config", serde(alias = "en", alias = "eng"))]
+    English,
+    /// German
+    #[cfg_attr(feature = "config", serde(alias = "de", alias = "ger", alias = "deu"))]
+    German,
+    /// Spanish
+    #[cfg_attr(feature = "config", serde(alias = "es", alias = "spa"))]
+    Spanish,
+    /// Italian
+    #[cfg_attr(feature = "config", serde(alias = "it", alias = "ita"))]
+    Italian,
+    /// Dutch
+    #[cfg_attr(feature = "config", serde(alias = "nl", alias = "dut", alias = "nld"))]
+    Dutch,
+    /// Portuguese
+    #[cfg_attr(feature = "config", serde(alias = "pt", alias = "por"))]
+    Portuguese,
+    /// Arabic
+    #[cfg_attr(feature = "config", serde(alias = "ar", alias = "ara"))]
+    Arabic,
+    /// Danish
+    #[cfg_attr(feature = "config", serde(alias = "da", alias = "dan"))]
+    Danish,
+    /// Norwegian
+    #[cfg_attr(feature = "config", serde(alias = "no", alias = "nor"))]
+    Norwegian,
+    /// Swedish
+    #[cfg_attr(feature = "config", serde(alias = "sv", alias = "swe"))]
+    Swedish
+}
+
+impl Language {
+    /// All the supported languages
+    pub const ALL: [Language; 11] = [
+        Language::French, Language::English, Language::German, Language::Spanish, Language::Italian, Language::Dutch,
+        Language::Portuguese, Language::Arabic, Language::Danish, Language::Norwegian, Language::Swedish
+    ];
+}
+
+/// Error returned when a language can't be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownLanguage(pub String);
+
+impl fmt::Display for UnknownLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown language {}", self.0)
+    }
+}
+
+impl std::error::Error for UnknownLanguage {}
+
+impl FromStr for Language {
+    type Err = UnknownLanguage;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_lowercase().as_str() {
+            "french" | "fr" | "fre" | "fra" => Ok(Language::French),
+            "english" | "en" | "eng" => Ok(Language::English),
+            "german" | "de" | "ger" | "deu" => Ok(Language::German),
+            "spanish" | "es" | "spa" => Ok(Language::Spanish),
+            "italian" | "it" | "ita" => Ok(Language::Italian),
+            "dutch" | "nl" | "dut" | "nld" => Ok(Language::Dutch),
+            "portuguese" | "pt" | "por" => Ok(Language::Portuguese),
+            "arabic" | "ar" | "ara" => Ok(Language::Arabic),
+            "danish" | "da" | "dan" => Ok(Language::Danish),
+            "norwegian" | "no" | "nor" => Ok(Language::Norwegian),
+            "swedish" | "sv" | "swe" => Ok(Language::Swedish),
+            _ => Err(UnknownLanguage(input.to_string()))
+        }
+    }
+}
@@ -17,6 +17,7 @@
 //! ## Usage
 //!  
 //! B-cleaner works with tokenized data. Tokenized data should contain punctuation.
+//! Raw strings can be tokenized with the [Tokenizer](struct.Tokenizer.html) or given directly to the `from_str` constructor of each cleaner.
 //! 
 //! ### Rust usage
 //! 
@@ -30,6 +31,12 @@
 //!     title.clean();
 //!     
 //!     assert_eq!(title.tokens(), &vec!["lorem", "ipsum", "dolor"]);
+//!
+//!     let mut title = TitleCleaner::from_str("Lorem ipsum dolor: sit amet");
+//!
+//!     title.clean();
+//!
+//!     assert_eq!(title.tokens(), &vec!["lorem", "ipsum", "dolor"]);
 //! }
 //! ```
 //! 
@@ -38,11 +45,74 @@
 //! * **stem** : Add stemming capabilities
 //! * **python** : Add bindings with python
 //! * **html** : Add HTML transformation capabilities
+//! * **config** : Load cleaner profiles from TOML or JSON files, see [CleanerConfig](struct.CleanerConfig.html)
+//! 
+//! ## Pipelines
+//! 
+//! Each cleaner applies a preset [Pipeline](struct.Pipeline.html) of [steps](trait.Step.html). Presets can be modified and custom steps can be registered, see [clean_with](struct.TitleCleaner.html#method.clean_with).
 //! 
 
 mod cleaners;
 pub use cleaners::*;
 
+mod tokenizer;
+pub use tokenizer::*;
+
+mod pipeline;
+pub use pipeline::*;
+
+mod language;
+pub use language::*;
+
+mod stopwords;
+pub use stopwords::*;
+
+mod isbd;
+pub use isbd::*;
+
+mod author;
+pub use author::*;
+
+mod corporate;
+pub use corporate::*;
+
+mod meeting;
+pub use meeting::*;
+
+mod isbn;
+pub use isbn::*;
+
+mod identifiers;
+pub use identifiers::*;
+
+mod date;
+pub use date::*;
+
+mod publisher;
+pub use publisher::*;
+
+mod place;
+pub use place::*;
+
+mod edition;
+pub use edition::*;
+
+mod numbering;
+pub use numbering::*;
+
+mod series;
+pub use series::*;
+
+mod roman;
+
+#[cfg(feature = "config")]
+mod config;
+#[cfg(feature = "config")]
+pub use config::*;
+
+#[cfg(feature = "stem")]
+pub use rust_stemmers::Algorithm;
+
 mod bindings;
 
 #[cfg(feature = "python")]
@@ -1,14 +1,48 @@
 use std::fs::File;
 use std::io::{BufReader, BufRead};
-mod cleaners;
 use std::borrow::Cow;
 use std::time::Instant;
 use std::error::Error;
 
-use cleaners::{TitleCleaner};
-use cleaners::Clean;
+use b_cleaner::{TitleCleaner, Pipeline};
+
+#[cfg(feature = "config")]
+use b_cleaner::Profile;
+
+/// Cleans a line and returns the number of tokens before cleaning and the cleaned tokens
+type LineCleaner = Box<dyn for<'a> Fn(&'a str) -> (usize, Vec<Cow<'a, str>>)>;
+
+/// Lines are cleaned as titles, the first argument can be the path of a TOML or JSON profile (config feature), lines are then cleaned as the Python binding does
+fn cleaner() -> Result<LineCleaner, Box<dyn Error>> {
+    #[cfg(feature = "config")]
+    {
+        if let Some(path) = std::env::args().nth(1) {
+            let profile = Profile::from_file(path)?;
+
+            return Ok(Box::new(move |line| {
+                let record = profile.clean_str(line);
+                let mut tokens = record.tokens;
+                tokens.extend(record.subtitle);
+
+                (b_cleaner::tokenize(line).len(), tokens)
+            }));
+        }
+    }
+
+    let pipeline = Pipeline::title(3);
+
+    Ok(Box::new(move |line| {
+        let mut title = TitleCleaner::from_str(line);
+        let total = title.tokens().len();
+
+        title.clean_with(&pipeline);
+
+        (total, title.tokens().clone())
+    }))
+}
 
 fn main() -> Result<(), Box<dyn Error>>{
+    let cleaner = cleaner()?;
     let file = File::open("titres.tsv")?;
     let buffered = BufReader::new(file);
     
@@ -17,24 +51,21 @@ fn main() -> Result<(), Box<dyn Error>>{
     let mut total_tokens : usize = 0;
 
     let now = Instant::now();
-    buffered.lines().map(|line| {
+    buffered.lines().try_for_each(|line| {
         let l = line?;
-        let title = l.split_whitespace().collect::<Vec<&str>>();
-        let mut t = TitleCleaner::new(&title);
+        let (total, tokens) = cleaner(&l);
 
-        total_tokens += title.len();
-        
-        t.clean();
+        total_tokens += total;
 
-        t.tokens().iter().for_each(|elem| {
+        tokens.iter().for_each(|elem| {
             match elem {
                 Cow::Borrowed(_) => borrowed_count += 1,
                 Cow::Owned(_) => owned_count += 1,
             }
         });
         
-        Ok(())
-    }).collect::<Result<(), Box<dyn Error>>>()?;
+        Ok::<(), Box<dyn Error>>(())
+    })?;
 
     println!("Total number of tokens {:?}", total_tokens);
     println!("Owned count {}", owned_count);
@@ -0,0 +1,353 @@
+use std::borrow::Cow;
+
+use crate::author::Year;
+use crate::cleaners::Clean;
+use crate::pipeline::*;
+use crate::tokenizer::Tokenizer;
+
+#[cfg(feature = "stem")]
+use rust_stemmers::Algorithm;
+
+/// Ordinal suffixes (`37th`, `2d`, `3e`, `2ème`, `5.`), compared in lowercase
+pub(crate) const ORDINAL_SUFFIXES: &[&str] = &["st", "nd", "rd", "th", "d", "e", "er", "re", "eme", "ème", "o", "a", "º", "ª", "."];
+
+/// Separators of the parts of a meeting qualifier
+const QUALIFIER_SEPARATORS: &[&str] = &[":", ";"];
+
+#[derive(Debug, Clone)]
+/// A struct dedicated to meeting names cleaning (MARC 111 / UNIMARC 712)
+///
+/// The number, the year and the place of a meeting are extracted from its qualifier (`(37th : 2020 : Vienna)`), ordinal numbers and years written inside the name (`37th International Conference`) are extracted as well.
+///
+/// The name and the place are cleaned by the [corporate pipeline](struct.Pipeline.html#method.corporate), so the [key](#method.key) is the same for every edition of a meeting.
+///
+/// ```
+/// # use b_cleaner::{MeetingCleaner, Clean, Year};
+/// # fn main() {
+/// let mut meeting = MeetingCleaner::from_str("International Conference on Machine Learning (37th : 2020 : Vienna)");
+///
+/// meeting.clean();
+///
+/// assert_eq!(meeting.name(), &vec!["international", "conference", "on", "machine", "learning"]);
+/// assert_eq!(meeting.number(), Some(37));
+/// assert_eq!(meeting.year(), Some(Year::new(2020)));
+/// assert_eq!(meeting.place(), &vec!["vienna"]);
+///
+/// let mut other_edition = MeetingCleaner::from_str("36th International Conference on Machine Learning, 2019");
+/// other_edition.clean();
+///
+/// assert_eq!(meeting.key(), other_edition.key());
+/// # }
+/// ```
+pub struct MeetingCleaner<'a> {
+    name: Vec<Cow<'a, str>>,
+    number: Option<u32>,
+    year: Option<Year>,
+    place: Vec<Cow<'a, str>>
+}
+
+impl <'a>MeetingCleaner<'a> {
+    /// Create a new MeetingCleaner
+    ///
+    /// ```
+    /// # use b_cleaner::MeetingCleaner;
+    /// # fn main() {
+    /// let tokens = vec!["Colloque", "(3e", ";", "1999", ";", "Paris)"];
+    /// let meeting = MeetingCleaner::new(&tokens);
+    ///
+    /// assert_eq!(meeting.name(), &vec!["Colloque"]);
+    /// assert_eq!(meeting.number(), Some(3));
+    /// # }
+    /// ```
+    pub fn new<R: AsRef<str>>(input: &'a [R]) -> Self {
+        MeetingCleaner::with_tokens(input.iter().map(|token| Cow::Borrowed(token.as_ref())))
+    }
+
+    /// Create a new MeetingCleaner from a raw string, the string is tokenized with the [Tokenizer](struct.Tokenizer.html)
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(input: &'a str) -> Self {
+        MeetingCleaner::with_tokens(Tokenizer::new(input).map(Cow::Borrowed))
+    }
+
+    fn with_tokens<I: Iterator<Item = Cow<'a, str>>>(tokens: I) -> Self {
+        let mut meeting = MeetingCleaner {
+            name: Vec::new(),
+            number: None,
+            year: None,
+            place: Vec::new()
+        };
+
+        let mut name = Vec::new();
+        let mut qualifiers = Vec::new();
+        let mut qualifier : Option<Vec<Cow<'a, str>>> = None;
+
+        for token in tokens {
+            let closing = token.ends_with(')');
+
+            match qualifier.as_mut() {
+                Some(qualifier) => qualifier.push(token),
+                None if token.starts_with('(') => qualifier = Some(vec![token]),
+                None => name.push(token)
+            }
+
+            if closing {
+                qualifiers.extend(qualifier.take());
+            }
+        }
+
+        qualifiers.extend(qualifier);
+
+        // The qualifier takes precedence over the numbers written inside the name
+        qualifiers.into_iter().for_each(|qualifier| meeting.add_qualifier(qualifier));
+
+        let year = name_year_position(&name);
+        let mut name = name.into_iter().enumerate().peekable();
+
+        while let Some((index, token)) = name.next() {
+            if meeting.add_name_token(token, name.peek().map(|(_, next)| next.as_ref()), year == Some(index)) {
+                name.next();
+            }
+        }
+
+        meeting
+    }
+
+    /// Ordinal numbers and the year found by [name_year_position] are extracted out of the name, returns true if the next token (the `.` of `5. Tagung`) belongs to the ordinal
+    fn add_name_token(&mut self, token: Cow<'a, str>, next: Option<&str>, year: bool) -> bool {
+        let word = token.trim_end_matches(',');
+
+        if let (None, Some((number, dotted))) = (self.number, parse_name_ordinal(word, next)) {
+            self.number = Some(number);
+
+            return dotted;
+        }
+
+        if let (None, Some(year)) = (self.year, parse_year(word).filter(|_| year)) {
+            self.year = Some(year);
+        } else {
+            self.name.push(token);
+        }
+
+        false
+    }
+
+    /// Parts of the qualifier are separated by `:` or `;`, each part is either a number, a year or a part of the place
+    fn add_qualifier(&mut self, qualifier: Vec<Cow<'a, str>>) {
+        let mut parts = vec![Vec::new()];
+
+        for token in qualifier {
+            let word = token.trim_matches(|c| c == '(' || c == ')');
+
+            if QUALIFIER_SEPARATORS.contains(&word) {
+                parts.push(Vec::new());
+            } else if !word.is_empty() {
+                parts.last_mut().expect("parts can't be empty").push(token);
+            }
+        }
+
+        for part in parts.into_iter().filter(|part| !part.is_empty()) {
+            let text = part.iter().map(|token| token.trim_matches(|c| c == '(' || c == ')')).collect::<Vec<&str>>().join(" ");
+
+            if let (None, Some(number)) = (self.number, parse_ordinal(&text, false)) {
+                self.number = Some(number);
+            } else if let (None, Some(year)) = (self.year, parse_year(&text)) {
+                self.year = Some(year);
+            } else {
+                self.place.extend(part);
+            }
+        }
+    }
+
+    /// Get the name of the meeting
+    pub fn name(&self) -> &Vec<Cow<'a, str>> {
+        &self.name
+    }
+
+    /// Get the number of the meeting
+    pub fn number(&self) -> Option<u32> {
+        self.number
+    }
+
+    /// Get the year of the meeting, meetings held over several years are represented by an imprecise year
+    pub fn year(&self) -> Option<Year> {
+        self.year
+    }
+
+    /// Get the place of the meeting
+    pub fn place(&self) -> &Vec<Cow<'a, str>> {
+        &self.place
+    }
+
+    /// Build a key out of the name of the meeting, the key doesn't depend on the number, the year or the place
+    pub fn key(&self) -> String {
+        self.name.join(" ")
+    }
+
+    /// Clean the name and the place with a custom [Pipeline](struct.Pipeline.html) instead of the preset one
+    pub fn clean_with(&mut self, pipeline: &Pipeline) -> &Self {
+        pipeline.apply(&mut self.name);
+        pipeline.apply(&mut self.place);
+
+        self
+    }
+}
+
+/// Parses an ordinal number (`37th`, `3e`, `2ème`), the suffix is optional when `require_suffix` is false
+pub(crate) fn parse_ordinal(input: &str, require_suffix: bool) -> Option<u32> {
+    let digits = input.chars().take_while(char::is_ascii_digit).count();
+    let suffix = input[digits..].to_lowercase();
+
+    if digits == 0 || digits > 3 || suffix.is_empty() && require_suffix {
+        return None;
+    }
+
+    if !suffix.is_empty() && !ORDINAL_SUFFIXES.contains(&suffix.as_str()) {
+        return None;
+    }
+
+    input[..digits].parse().ok()
+}
+
+/// Parses an ordinal number written inside a name, returns the number and whether the next token is its `.` mark
+///
+/// Bare digits are only read as an ordinal when followed by a separate `.` token (`5. Internationale Tagung`), a `d` suffix isn't an ordinal in front of a lowercase word (`3D printing`)
+fn parse_name_ordinal(word: &str, next: Option<&str>) -> Option<(u32, bool)> {
+    let digits = word.chars().take_while(char::is_ascii_digit).count();
+
+    if digits == word.len() {
+        return parse_ordinal(word, false).filter(|_| next == Some(".")).map(|number| (number, true));
+    }
+
+    let before_lowercase = next.and_then(|next| next.chars().next()).is_some_and(char::is_lowercase);
+
+    if word[digits..].eq_ignore_ascii_case("d") && before_lowercase {
+        return None;
+    }
+
+    parse_ordinal(word, true).map(|number| (number, false))
+}
+
+/// Finds the year of the meeting written inside its name, the year starts the name (`2020 Workshop on …`), ends it after a comma (`Conference on 3D printing, 2019`) or is next to the ordinal (`5th 2020 Workshop`), other years are words of the name (`Symposium on Apollo 2001`)
+fn name_year_position(name: &[Cow<str>]) -> Option<usize> {
+    let word = |index: usize| name[index].trim_end_matches(',');
+    let is_ordinal = |index: usize| parse_name_ordinal(word(index), name.get(index + 1).map(|next| next.as_ref())).is_some();
+
+    (0..name.len()).filter(|index| parse_year(word(*index)).is_some()).find(|&index| {
+        let after_comma = index + 1 == name.len() && index > 0 && name[index - 1].ends_with(',');
+        let after_ordinal = (index > 0 && is_ordinal(index - 1)) || (index > 1 && name[index - 1] == "." && is_ordinal(index - 2));
+        let before_ordinal = index + 1 < name.len() && is_ordinal(index + 1);
+
+        index == 0 || after_comma || after_ordinal || before_ordinal
+    })
+}
+
+/// Parses a year (`2020`) or a range of years (`1987-1988`)
+fn parse_year(input: &str) -> Option<Year> {
+    let mut years = input.split('-').map(|year| year.trim());
+    let start = years.next().filter(|year| year.len() == 4).and_then(|year| year.parse::<i32>().ok())?;
+
+    match years.next() {
+        Some(end) if years.next().is_none() => {
+            let end : i32 = end.parse().ok()?;
+            let end = match end {
+                0..=9 => start - start % 10 + end,
+                10..=99 => start - start % 100 + end,
+                _ => end
+            };
+
+            Some(Year::between(start, end))
+        },
+        None => Some(Year::new(start)),
+        _ => None
+    }
+}
+
+impl <'a>Clean for MeetingCleaner<'a> {
+    fn clean(&mut self) -> &Self {
+        self.clean_with(&Pipeline::preset(Preset::Corporate, Pipeline::corporate))
+    }
+
+    #[cfg(feature = "stem")]
+    fn stem(&mut self, lang: Algorithm) -> &Self {
+        Stem::new(lang).apply(&mut self.name);
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_qualifier_parts() {
+        let mut meeting = MeetingCleaner::from_str("Symposium on Logic (2nd: 1987-88: Berlin, Germany)");
+        meeting.clean();
+
+        assert_eq!(meeting.key(), "symposium on logic");
+        assert_eq!(meeting.number(), Some(2));
+        assert_eq!(meeting.year(), Some(Year::between(1987, 1988)));
+        assert_eq!(meeting.place(), &vec!["berlin", "germany"]);
+    }
+
+    #[test]
+    fn test_qualifier_takes_precedence() {
+        let mut meeting = MeetingCleaner::from_str("Conference on 3D printing (2nd : 2019 : Paris)");
+        meeting.clean();
+
+        assert_eq!(meeting.key(), "conference on 3d printing");
+        assert_eq!(meeting.number(), Some(2));
+        assert_eq!(meeting.year(), Some(Year::new(2019)));
+        assert_eq!(meeting.place(), &vec!["paris"]);
+
+        let mut meeting = MeetingCleaner::from_str("Colloque (Paris : Sorbonne)");
+        meeting.clean();
+
+        assert_eq!(meeting.key(), "colloque");
+        assert_eq!(meeting.place(), &vec!["paris", "sorbonne"]);
+    }
+
+    #[test]
+    fn test_name_ordinals() {
+        let mut meeting = MeetingCleaner::from_str("Conference on 3D printing, 2019");
+        meeting.clean();
+
+        assert_eq!(meeting.key(), "conference on 3d printing");
+        assert_eq!(meeting.number(), None);
+        assert_eq!(meeting.year(), Some(Year::new(2019)));
+
+        let mut meeting = MeetingCleaner::from_str("5. Internationale Tagung (Berlin)");
+        meeting.clean();
+
+        assert_eq!(meeting.key(), "internationale tagung");
+        assert_eq!(meeting.number(), Some(5));
+        assert_eq!(meeting.place(), &vec!["berlin"]);
+
+        let mut meeting = MeetingCleaner::from_str("2d International Congress");
+        meeting.clean();
+
+        assert_eq!(meeting.key(), "international congress");
+        assert_eq!(meeting.number(), Some(2));
+
+        let mut meeting = MeetingCleaner::from_str("Symposium on Apollo 2001 (2nd : Houston)");
+        meeting.clean();
+
+        assert_eq!(meeting.key(), "symposium on apollo 2001");
+        assert_eq!(meeting.year(), None);
+
+        let mut meeting = MeetingCleaner::from_str("2020 Workshop on Logic (5th : Paris)");
+        meeting.clean();
+
+        assert_eq!(meeting.key(), "workshop on logic");
+        assert_eq!(meeting.year(), Some(Year::new(2020)));
+    }
+
+    #[test]
+    fn test_ordinals() {
+        assert_eq!(parse_ordinal("3e", true), Some(3));
+        assert_eq!(parse_ordinal("21st", true), Some(21));
+        assert_eq!(parse_ordinal("2020", true), None);
+        assert_eq!(parse_ordinal("12", false), Some(12));
+        assert_eq!(parse_ordinal("4x4", false), None);
+    }
+}
@@ -0,0 +1,260 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use unidecode::unidecode;
+
+use crate::edition::SPELLED_ORDINALS;
+use crate::meeting::parse_ordinal;
+use crate::roman::{parse_roman, parse_roman_ordinal};
+use crate::tokenizer::is_punctuation;
+
+/// Words introducing a part number, compared once lowercased and unidecoded without the final dot
+const DESIGNATIONS: &[(&str, Designation)] = &[
+    ("tome", Designation::Volume), ("t", Designation::Volume), ("tomo", Designation::Volume), ("tomus", Designation::Volume),
+    ("vol", Designation::Volume), ("vols", Designation::Volume), ("volume", Designation::Volume), ("v", Designation::Volume),
+    ("bd", Designation::Volume), ("band", Designation::Volume), ("deel", Designation::Volume),
+    ("part", Designation::Part), ("pt", Designation::Part), ("partie", Designation::Part), ("teil", Designation::Part),
+    ("parte", Designation::Part), ("pars", Designation::Part),
+    ("livre", Designation::Book), ("liv", Designation::Book), ("book", Designation::Book), ("bk", Designation::Book),
+    ("buch", Designation::Book), ("libro", Designation::Book), ("liber", Designation::Book),
+    ("fasc", Designation::Fascicle), ("fascicule", Designation::Fascicle), ("fascicle", Designation::Fascicle),
+    ("fascicolo", Designation::Fascicle), ("heft", Designation::Fascicle), ("cahier", Designation::Fascicle),
+    ("no", Designation::Number), ("n°", Designation::Number), ("nº", Designation::Number), ("nr", Designation::Number),
+    ("num", Designation::Number), ("numero", Designation::Number), ("number", Designation::Number), ("nummer", Designation::Number)
+];
+
+/// Numbers following a designation are accepted up to this number of digits
+const MAX_DIGITS: usize = 5;
+
+/// Part numbers written with Roman numerals are only accepted up to this number, to skip words written in uppercase (`MIX`)
+const MAX_ROMAN_PART: u32 = 100;
+
+/// Kind of a part designation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Designation {
+    /// A volume (`tome`, `vol.`, `Bd.`)
+    Volume,
+    /// A part (`part`, `partie`, `Teil`)
+    Part,
+    /// A book (`livre`, `book`, `Buch`)
+    Book,
+    /// A fascicle (`fasc.`, `Heft`)
+    Fascicle,
+    /// A number (`no.`, `n°`, `Nr.`)
+    Number,
+    /// A bare number ending the title (`Histoire de France. II`)
+    Unspecified
+}
+
+impl fmt::Display for Designation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let label = match self {
+            Designation::Volume => "volume",
+            Designation::Part => "part",
+            Designation::Book => "book",
+            Designation::Fascicle => "fascicle",
+            Designation::Number => "number",
+            Designation::Unspecified => "unspecified"
+        };
+
+        write!(f, "{}", label)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// The numbering of a part of a title (`Tome 2`, `vol. III`, `Band 4`)
+///
+/// Numbers are read from digits, Roman numerals and spelled-out ordinals (`Livre premier`).
+///
+/// ```
+/// # use b_cleaner::{Designation, Numbering};
+/// # fn main() {
+/// let numbering = Numbering::new(Designation::Volume, 3);
+///
+/// assert_eq!(numbering.designation(), Designation::Volume);
+/// assert_eq!(numbering.number(), 3);
+/// assert_eq!(numbering.to_string(), "volume 3");
+/// # }
+/// ```
+pub struct Numbering {
+    designation: Designation,
+    number: u32
+}
+
+impl Numbering {
+    /// Create a new Numbering
+    pub fn new(designation: Designation, number: u32) -> Self {
+        Numbering {
+            designation,
+            number
+        }
+    }
+
+    /// Get the kind of designation
+    pub fn designation(&self) -> Designation {
+        self.designation
+    }
+
+    /// Get the number of the part
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+}
+
+impl fmt::Display for Numbering {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.designation {
+            Designation::Unspecified => write!(f, "{}", self.number),
+            designation => write!(f, "{} {}", designation, self.number)
+        }
+    }
+}
+
+/// Returns the designation introduced by a word (`Tome`, `vol.`, `Bd`)
+fn parse_designation(word: &str) -> Option<Designation> {
+    let word = word.trim_end_matches('.').to_lowercase();
+    let normalized = unidecode(&word);
+
+    DESIGNATIONS.iter()
+        .find(|(designation, _)| *designation == word || *designation == normalized)
+        .map(|(_, designation)| *designation)
+}
+
+/// Parses a part number, numbers following a designation may be longer, written with lowercase Roman numerals or spelled-out
+pub(crate) fn parse_number(token: &str, designated: bool) -> Option<u32> {
+    let token = token.trim_end_matches('.');
+
+    if designated && !token.is_empty() && token.len() <= MAX_DIGITS && token.chars().all(|c| c.is_ascii_digit()) {
+        return token.parse().ok();
+    }
+
+    // A single numeral followed by a suffix is most likely a word (`Le`, `Ce`)
+    let roman_word = token.chars().count() <= 2 && !token.chars().all(|c| c.is_ascii_uppercase());
+    let number = parse_ordinal(token, false)
+        .or_else(|| parse_roman_ordinal(token).filter(|number| *number <= MAX_ROMAN_PART && !roman_word));
+
+    if !designated {
+        return number;
+    }
+
+    let normalized = unidecode(&token.to_lowercase());
+
+    number
+        .or_else(|| parse_roman(token).filter(|number| *number <= MAX_ROMAN_PART))
+        .or_else(|| SPELLED_ORDINALS.iter().find(|(ordinal, _)| *ordinal == normalized).map(|(_, number)| *number))
+}
+
+/// Parses a designation and its number at the start of the tokens, returns the numbering and the count of tokens read
+fn parse_part(tokens: &[Cow<str>]) -> Option<(Numbering, usize)> {
+    let first = tokens.first()?;
+
+    // Designation and number written as a single token (`vol.3`)
+    if let Some((word, number)) = first.split_once('.').filter(|(_, number)| !number.is_empty()) {
+        let designation = parse_designation(word)?;
+
+        return parse_number(number, true).map(|number| (Numbering::new(designation, number), 1));
+    }
+
+    let len = if tokens.get(1).is_some_and(|token| token == ".") { 2 } else { 1 };
+
+    // Number preceding the designation (`2. Teil`, `IIe partie`), single letters are left out as `3 t.` is a count of volumes
+    let postfix = tokens.get(len)
+        .filter(|token| token.trim_end_matches('.').chars().count() > 1)
+        .and_then(|token| parse_designation(token));
+
+    if let (Some(number), Some(designation)) = (parse_number(first, false), postfix) {
+        return Some((Numbering::new(designation, number), len + 1));
+    }
+
+    let designation = parse_designation(first)?;
+    let number = tokens.get(len).and_then(|token| parse_number(token, true))?;
+
+    if first.trim_end_matches('.').chars().count() == 1 && is_initial(&tokens[len..]) {
+        return None;
+    }
+
+    Some((Numbering::new(designation, number), len + 1))
+}
+
+/// Whether the number following a one-letter designation is rather an initial (`V. I. Lenine`), such a number is written with digits or at least two Roman numerals and isn't followed by a dot and a capitalized word
+fn is_initial(tokens: &[Cow<str>]) -> bool {
+    let number = tokens[0].trim_end_matches('.');
+    let (dot, next) = match tokens[0].ends_with('.') {
+        true => (true, tokens.get(1)),
+        false => (tokens.get(1).is_some_and(|token| token == "."), tokens.get(2))
+    };
+
+    let numeral = number.chars().all(|c| c.is_ascii_digit()) || number.chars().count() > 1;
+    let before_name = dot && next.and_then(|token| token.chars().next()).is_some_and(char::is_uppercase);
+
+    !numeral || before_name
+}
+
+/// Takes the part designations (`Tome 2`, `vol. III`) out of a title
+///
+/// A bare number ending the title is kept as an unspecified part when it follows a comma or a dot (`Histoire de France. II`), but not otherwise (`Henri IV`).
+pub(crate) fn split_off_numbering<'a>(tokens: Vec<Cow<'a, str>>) -> (Vec<Cow<'a, str>>, Vec<Numbering>) {
+    let mut consumed = vec![false; tokens.len()];
+    let mut numbering = Vec::new();
+    let mut index = 0;
+
+    while index < tokens.len() {
+        match parse_part(&tokens[index..]) {
+            Some((part, len)) => {
+                consumed[index..index + len].iter_mut().for_each(|consumed| *consumed = true);
+                numbering.push(part);
+                index += len;
+            },
+            None => index += 1
+        }
+    }
+
+    let is_word = |index: &usize| !consumed[*index] && !tokens[*index].chars().all(is_punctuation);
+
+    if let Some(last) = (0..tokens.len()).rev().find(is_word) {
+        let after_separator = last > 0 && !consumed[last - 1] && (tokens[last - 1] == "," || tokens[last - 1] == ".");
+
+        if let Some(number) = parse_number(&tokens[last], false).filter(|_| after_separator) {
+            consumed[last] = true;
+            numbering.push(Numbering::new(Designation::Unspecified, number));
+        }
+    }
+
+    let title = tokens.into_iter()
+        .zip(consumed)
+        .filter(|(_, consumed)| !consumed)
+        .map(|(token, _)| token)
+        .skip_while(|token| token.chars().all(is_punctuation))
+        .collect();
+
+    (title, numbering)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokenizer::Tokenizer;
+
+    fn split(input: &str) -> (Vec<String>, Vec<String>) {
+        let (title, numbering) = split_off_numbering(Tokenizer::new(input).map(Cow::Borrowed).collect());
+
+        (title.iter().map(|token| token.to_string()).collect(), numbering.iter().map(Numbering::to_string).collect())
+    }
+
+    #[test]
+    fn test_split_off_numbering() {
+        assert_eq!(split("Histoire de France. Tome 2"), (vec!["Histoire".to_string(), "de".to_string(), "France".to_string(), ".".to_string()], vec!["volume 2".to_string()]));
+        assert_eq!(split("Œuvres complètes, vol. III").1, vec!["volume 3"]);
+        assert_eq!(split("Band 4").1, vec!["volume 4"]);
+        assert_eq!(split("Le Seigneur des anneaux. Livre premier").1, vec!["book 1"]);
+        assert_eq!(split("Faust. 2. Teil").1, vec!["part 2"]);
+        assert_eq!(split("Histoire de France. II").1, vec!["2"]);
+        assert_eq!(split("Tome 2 : Les Misérables").0, vec!["Les", "Misérables"]);
+        assert_eq!(split("V. 2").1, vec!["volume 2"]);
+        assert_eq!(split("V. I. Lenine : oeuvres choisies"), (vec!["V".to_string(), ".".to_string(), "I".to_string(), ".".to_string(), "Lenine".to_string(), ":".to_string(), "oeuvres".to_string(), "choisies".to_string()], Vec::new()));
+        assert_eq!(split("Histoire. T. II").1, vec!["volume 2"]);
+        assert_eq!(split("Henri IV").1, Vec::<String>::new());
+        assert_eq!(split("Paris, 1900").1, Vec::<String>::new());
+        assert_eq!(split("Le Tome des rêves").1, Vec::<String>::new());
+    }
+}
@@ -0,0 +1,835 @@
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::cleaners::*;
+use crate::roman::recognize_roman;
+use crate::stopwords::{RemoveStopwords, SplitElisions, normalize_word};
+
+#[cfg(feature = "stem")]
+use rust_stemmers::{Algorithm, Stemmer};
+
+#[cfg(feature = "config")]
+use serde::{Deserialize, Serialize};
+
+/// A cleaning step applied to a list of tokens
+///
+/// Steps are chained by a [Pipeline](struct.Pipeline.html). A step can be implemented for any struct, it receives all the tokens at once and can modify, remove or insert tokens.
+///
+/// ```
+/// # use std::borrow::Cow;
+/// # use b_cleaner::{Step, Pipeline};
+/// # fn main() {
+/// struct RemoveLorem;
+///
+/// impl Step for RemoveLorem {
+///     fn name(&self) -> &str {
+///         "remove_lorem"
+///     }
+///
+///     fn apply<'a>(&self, tokens: &mut Vec<Cow<'a, str>>) {
+///         tokens.retain(|token| token != "lorem");
+///     }
+/// }
+///
+/// let mut pipeline = Pipeline::text(3);
+/// pipeline.add_step(RemoveLorem);
+///
+/// let mut tokens = vec![Cow::Borrowed("Lorem"), Cow::Borrowed("ipsum")];
+/// pipeline.apply(&mut tokens);
+///
+/// assert_eq!(tokens, vec!["ipsum"]);
+/// # }
+/// ```
+pub trait Step {
+    /// Name of the step, used to find a step inside a pipeline
+    fn name(&self) -> &str;
+
+    /// Apply the step to a list of tokens
+    fn apply<'a>(&self, tokens: &mut Vec<Cow<'a, str>>);
+}
+
+/// A step applying a function to each token
+///
+/// ```
+/// # use std::borrow::Cow;
+/// # use b_cleaner::{TokenStep, Pipeline};
+/// # fn main() {
+/// let mut pipeline = Pipeline::new();
+/// pipeline.add_step(TokenStep::new("reverse", |token: &mut Cow<str>| *token = Cow::Owned(token.chars().rev().collect())));
+///
+/// let mut tokens = vec![Cow::Borrowed("lorem")];
+/// pipeline.apply(&mut tokens);
+///
+/// assert_eq!(tokens, vec!["merol"]);
+/// # }
+/// ```
+pub struct TokenStep<F> {
+    name: String,
+    function: F
+}
+
+impl <F>TokenStep<F> where F: Fn(&mut Cow<str>) {
+    /// Create a new TokenStep from a name and a function
+    pub fn new<N: Into<String>>(name: N, function: F) -> Self {
+        TokenStep {
+            name: name.into(),
+            function
+        }
+    }
+}
+
+impl <F>Step for TokenStep<F> where F: Fn(&mut Cow<str>) {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn apply<'a>(&self, tokens: &mut Vec<Cow<'a, str>>) {
+        tokens.iter_mut().for_each(|token| (self.function)(token));
+    }
+}
+
+macro_rules! token_step {
+    ($(#[$meta:meta])* $step:ident, $name:expr, $function:path) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+        pub struct $step;
+
+        impl Step for $step {
+            fn name(&self) -> &str {
+                $name
+            }
+
+            fn apply<'a>(&self, tokens: &mut Vec<Cow<'a, str>>) {
+                tokens.iter_mut().for_each($function);
+            }
+        }
+    };
+}
+
+token_step!(
+    /// Transforms tokens to lowercase
+    Lowercase, "lowercase", token_to_lowercase
+);
+
+token_step!(
+    /// Replaces accented chars by their ASCII equivalent
+    Unidecode, "unidecode", unidecode_token
+);
+
+token_step!(
+    /// Removes non ASCII chars
+    RemoveNonAscii, "remove_non_ascii", remove_token_non_ascii_chars
+);
+
+token_step!(
+    /// Removes punctuation and digits, except `-` which can be used to join words
+    RemoveDigitAndPunctuation, "remove_digit_and_punctuation", remove_token_digit_and_punctuation
+);
+
+token_step!(
+    /// Removes punctuation, except `-` which can be used to join words
+    RemovePunctuation, "remove_punctuation", remove_token_punctuation
+);
+
+token_step!(
+    /// Removes extra white spaces at the begining and at the end of tokens
+    Trim, "trim", token_trim
+);
+
+#[cfg(feature = "html")]
+token_step!(
+    /// Replaces HTML encoded entities with their decoded counterpart (html feature)
+    DecodeHtmlEntities, "decode_html_entities", decode_token_html_entities
+);
+
+/// Removes tokens smaller or equal to a length threshold
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MinLength(pub usize);
+
+impl Step for MinLength {
+    fn name(&self) -> &str {
+        "min_length"
+    }
+
+    fn apply<'a>(&self, tokens: &mut Vec<Cow<'a, str>>) {
+        tokens.retain(|token| token.len() > self.0);
+    }
+}
+
+/// Removes tokens smaller or equal to a length threshold, except numbers (`22`) which are kept whatever their length
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MinLengthExceptNumbers(pub usize);
+
+impl Step for MinLengthExceptNumbers {
+    fn name(&self) -> &str {
+        "min_length_except_numbers"
+    }
+
+    fn apply<'a>(&self, tokens: &mut Vec<Cow<'a, str>>) {
+        tokens.retain(|token| token.len() > self.0 || !token.is_empty() && token.chars().all(|c| c.is_ascii_digit()));
+    }
+}
+
+/// Placeholder replacing numbers with the [NumericPolicy::Placeholder](enum.NumericPolicy.html#variant.Placeholder) policy
+const NUMBER_PLACEHOLDER: &str = "#";
+
+/// What to do with the digits of tokens, see [HandleNumbers](struct.HandleNumbers.html)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "config", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "config", serde(rename_all = "snake_case"))]
+pub enum NumericPolicy {
+    /// Digits are removed from all the tokens
+    #[default]
+    Drop,
+    /// Digits are kept (`1984`, `catch-22`)
+    Keep,
+    /// Numbers (`1984`) are kept, digits are removed from the other tokens (`catch-22` becomes `catch-`)
+    KeepStandalone,
+    /// Numbers are replaced by `#`, inside tokens as well (`catch-22` becomes `catch-#`)
+    Placeholder
+}
+
+impl NumericPolicy {
+    /// Numbers converted from Roman numerals must survive, digits are then kept standalone rather than dropped
+    pub(crate) fn with_roman_numerals(self, roman_numerals: Option<RomanNumerals>) -> Self {
+        match (self, roman_numerals) {
+            (NumericPolicy::Drop, Some(RomanNumerals::Convert)) => NumericPolicy::KeepStandalone,
+            (policy, _) => policy
+        }
+    }
+}
+
+/// Removes, keeps or replaces the digits of tokens according to a [NumericPolicy](enum.NumericPolicy.html)
+///
+/// ```
+/// # use std::borrow::Cow;
+/// # use b_cleaner::{HandleNumbers, NumericPolicy, Step};
+/// # fn main() {
+/// let tokens = vec![Cow::Borrowed("1984"), Cow::Borrowed("catch-22")];
+///
+/// let mut standalone = tokens.clone();
+/// HandleNumbers(NumericPolicy::KeepStandalone).apply(&mut standalone);
+/// assert_eq!(standalone, vec!["1984", "catch-"]);
+///
+/// let mut placeholder = tokens.clone();
+/// HandleNumbers(NumericPolicy::Placeholder).apply(&mut placeholder);
+/// assert_eq!(placeholder, vec!["#", "catch-#"]);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HandleNumbers(pub NumericPolicy);
+
+impl HandleNumbers {
+    fn replace_digits(token: &mut Cow<str>) {
+        if !token.chars().any(|c| c.is_ascii_digit()) {
+            return;
+        }
+
+        let mut replaced = String::with_capacity(token.len());
+        let mut previous_digit = false;
+
+        for c in token.chars() {
+            match c.is_ascii_digit() {
+                true if !previous_digit => replaced.push_str(NUMBER_PLACEHOLDER),
+                true => (),
+                false => replaced.push(c)
+            }
+
+            previous_digit = c.is_ascii_digit();
+        }
+
+        *token = Cow::Owned(replaced);
+    }
+}
+
+impl Step for HandleNumbers {
+    fn name(&self) -> &str {
+        "handle_numbers"
+    }
+
+    fn apply<'a>(&self, tokens: &mut Vec<Cow<'a, str>>) {
+        match self.0 {
+            NumericPolicy::Drop => tokens.iter_mut().for_each(remove_token_digit),
+            NumericPolicy::Keep => (),
+            NumericPolicy::KeepStandalone => tokens.iter_mut()
+                .filter(|token| !token.chars().all(|c| c.is_ascii_digit()))
+                .for_each(remove_token_digit),
+            NumericPolicy::Placeholder => tokens.iter_mut().for_each(HandleNumbers::replace_digits)
+        }
+    }
+}
+
+/// Removes empty tokens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RemoveEmpty;
+
+impl Step for RemoveEmpty {
+    fn name(&self) -> &str {
+        "remove_empty"
+    }
+
+    fn apply<'a>(&self, tokens: &mut Vec<Cow<'a, str>>) {
+        tokens.retain(|token| !token.is_empty());
+    }
+}
+
+/// Removes the tokens following the first strong punctuation mark (`.`, `:`, `?`, `!`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SplitAtStrongPunctuation;
+
+impl Step for SplitAtStrongPunctuation {
+    fn name(&self) -> &str {
+        "split_at_strong_punctuation"
+    }
+
+    fn apply<'a>(&self, tokens: &mut Vec<Cow<'a, str>>) {
+        tokens_split_at_strong_punctuation(tokens);
+    }
+}
+
+/// Splits tokens joined by hyphens (`springer-verlag`) into several tokens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SplitAtHyphens;
+
+impl Step for SplitAtHyphens {
+    fn name(&self) -> &str {
+        "split_at_hyphens"
+    }
+
+    fn apply<'a>(&self, tokens: &mut Vec<Cow<'a, str>>) {
+        if !tokens.iter().any(|token| token.contains('-')) {
+            return;
+        }
+
+        *tokens = tokens.drain(..).flat_map(|token| -> Vec<Cow<'a, str>> {
+            match token {
+                Cow::Borrowed(token) => token.split('-').filter(|part| !part.is_empty()).map(Cow::Borrowed).collect(),
+                Cow::Owned(token) if token.contains('-') => token.split('-').filter(|part| !part.is_empty()).map(|part| Cow::Owned(part.to_string())).collect(),
+                token => vec![token]
+            }
+        }).collect();
+    }
+}
+
+/// Removes tokens between an opening and a closing delimiter
+///
+/// ```
+/// # use std::borrow::Cow;
+/// # use b_cleaner::{RemoveBetweenDelimiters, Step};
+/// # fn main() {
+/// let mut tokens = vec![Cow::Borrowed("lorem"), Cow::Borrowed("(ipsum)"), Cow::Borrowed("dolor")];
+/// RemoveBetweenDelimiters::new("(", ")").apply(&mut tokens);
+///
+/// assert_eq!(tokens, vec!["lorem", "dolor"]);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoveBetweenDelimiters {
+    open: String,
+    close: String
+}
+
+impl RemoveBetweenDelimiters {
+    /// Create a new RemoveBetweenDelimiters step
+    pub fn new<S: Into<String>>(open: S, close: S) -> Self {
+        RemoveBetweenDelimiters {
+            open: open.into(),
+            close: close.into()
+        }
+    }
+}
+
+impl Step for RemoveBetweenDelimiters {
+    fn name(&self) -> &str {
+        "remove_between_delimiters"
+    }
+
+    fn apply<'a>(&self, tokens: &mut Vec<Cow<'a, str>>) {
+        remove_tokens_between_delimiters(tokens, (&self.open, &self.close));
+    }
+}
+
+/// Replaces words by their canonical form, a canonical form can be made of several words
+///
+/// Words are normalized (lowercase, unidecode), the step should be applied to normalized tokens.
+///
+/// ```
+/// # use std::borrow::Cow;
+/// # use b_cleaner::{ReplaceWords, Step};
+/// # fn main() {
+/// let mut replace = ReplaceWords::new();
+/// replace.add_synonyms("university", &["université", "univ"]);
+/// replace.add_word("cnrs", "centre national de la recherche scientifique");
+///
+/// let mut tokens = vec![Cow::Borrowed("univ"), Cow::Borrowed("cnrs")];
+/// replace.apply(&mut tokens);
+///
+/// assert_eq!(tokens, vec!["university", "centre", "national", "de", "la", "recherche", "scientifique"]);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ReplaceWords {
+    words: HashMap<String, Vec<String>>
+}
+
+impl ReplaceWords {
+    /// Create a new empty ReplaceWords step
+    pub fn new() -> Self {
+        ReplaceWords::default()
+    }
+
+    /// Replace a word by another one
+    pub fn add_word(&mut self, word: &str, replacement: &str) -> &mut Self {
+        let replacement = replacement.split_whitespace().map(normalize_word).collect();
+
+        self.words.insert(normalize_word(word), replacement);
+        self
+    }
+
+    /// Replace some variants by a canonical form
+    pub fn add_synonyms<S: AsRef<str>>(&mut self, canonical: &str, variants: &[S]) -> &mut Self {
+        self.add_word(canonical, canonical);
+        variants.iter().for_each(|variant| {
+            self.add_word(variant.as_ref(), canonical);
+        });
+
+        self
+    }
+
+    /// Returns true if a normalized token is a known word, either a variant or a canonical form
+    pub fn contains(&self, token: &str) -> bool {
+        self.words.contains_key(token)
+    }
+}
+
+impl Step for ReplaceWords {
+    fn name(&self) -> &str {
+        "replace_words"
+    }
+
+    fn apply<'a>(&self, tokens: &mut Vec<Cow<'a, str>>) {
+        if !tokens.iter().any(|token| self.words.contains_key(token.as_ref())) {
+            return;
+        }
+
+        *tokens = tokens.drain(..).flat_map(|token| match self.words.get(token.as_ref()) {
+            Some(replacement) => replacement.iter().map(|word| Cow::Owned(word.clone())).collect(),
+            None => vec![token]
+        }).collect();
+    }
+}
+
+/// Recognizes Roman numerals (`Louis XIV`, `XIXe siècle`) and converts them to Arabic numbers or removes them
+///
+/// Numerals are recognized out of their case, the step must be applied before the lowercase transformation. French and Italian ordinal suffixes are dropped while converting (`XIXe` becomes `19`), so numbers are kept by the numeric steps. Lowercase words (`mix`, `did`), single numerals without suffix (`I`, `X`) and common acronyms (`CD`, `XL`) are not numerals.
+///
+/// ```
+/// # use std::borrow::Cow;
+/// # use b_cleaner::{RomanNumerals, Step};
+/// # fn main() {
+/// let mut tokens = vec![Cow::Borrowed("Louis"), Cow::Borrowed("XIV"), Cow::Borrowed("XIXe"), Cow::Borrowed("mix"), Cow::Borrowed("I")];
+/// RomanNumerals::Convert.apply(&mut tokens);
+///
+/// assert_eq!(tokens, vec!["Louis", "14", "19", "mix", "I"]);
+///
+/// let mut tokens = vec![Cow::Borrowed("Napoléon"), Cow::Borrowed("Ier"), Cow::Borrowed("Le"), Cow::Borrowed("CD")];
+/// RomanNumerals::Remove.apply(&mut tokens);
+///
+/// assert_eq!(tokens, vec!["Napoléon", "Le", "CD"]);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "config", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "config", serde(rename_all = "lowercase"))]
+pub enum RomanNumerals {
+    /// Converts numerals to Arabic numbers
+    Convert,
+    /// Removes numerals
+    Remove
+}
+
+impl Step for RomanNumerals {
+    fn name(&self) -> &str {
+        "roman_numerals"
+    }
+
+    fn apply<'a>(&self, tokens: &mut Vec<Cow<'a, str>>) {
+        match self {
+            RomanNumerals::Convert => tokens.iter_mut().for_each(|token| {
+                if let Some((number, _)) = recognize_roman(token) {
+                    *token = Cow::Owned(number.to_string());
+                }
+            }),
+            RomanNumerals::Remove => tokens.retain(|token| recognize_roman(token).is_none())
+        }
+    }
+}
+
+#[cfg(feature = "stem")]
+/// Stems tokens (stem feature), stemming implies huge performance downside
+pub struct Stem(Stemmer);
+
+#[cfg(feature = "stem")]
+impl Stem {
+    /// Create a new Stem step for a language
+    pub fn new(lang: Algorithm) -> Self {
+        Stem(Stemmer::create(lang))
+    }
+}
+
+#[cfg(feature = "stem")]
+impl Step for Stem {
+    fn name(&self) -> &str {
+        "stem"
+    }
+
+    fn apply<'a>(&self, tokens: &mut Vec<Cow<'a, str>>) {
+        tokens.iter_mut().for_each(|token| {
+            let stem = self.0.stem(token);
+
+            if stem != *token {
+                *token = Cow::Owned(stem.into_owned());
+            }
+        });
+    }
+}
+
+/// Preset pipelines shared by the cleaners, see [Pipeline::preset](struct.Pipeline.html#method.preset)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Preset {
+    /// Text cleaner without stopwords : token min length, numeric policy and Roman numerals
+    Text(usize, NumericPolicy, Option<RomanNumerals>),
+    /// Title cleaner without stopwords : token min length, numeric policy and Roman numerals
+    Title(usize, NumericPolicy, Option<RomanNumerals>),
+    /// The author pipeline
+    Author,
+    /// Normalization of the parts of a parsed name
+    AuthorName,
+    /// The corporate pipeline
+    Corporate,
+    /// Corporate cleaner with its bundled synonyms and stopwords
+    CorporateCleaner,
+    /// Publisher cleaner with its bundled acronyms, legal forms and generic words
+    PublisherCleaner
+}
+
+thread_local! {
+    /// Steps aren't `Send`, presets are built once per thread
+    static PRESETS: RefCell<HashMap<Preset, Rc<Pipeline>>> = RefCell::new(HashMap::new());
+}
+
+/// An ordered list of cleaning steps
+///
+/// Preset pipelines are used by the [TextCleaner](struct.TextCleaner.html), the [TitleCleaner](struct.TitleCleaner.html) and the [AuthorCleaner](struct.AuthorCleaner.html).
+/// Presets can be modified: steps can be added, inserted, removed or reordered.
+///
+/// ```
+/// # use b_cleaner::{Pipeline, TitleCleaner, Clean};
+/// # fn main() {
+/// let mut pipeline = Pipeline::title(3);
+/// pipeline.remove_step("split_at_strong_punctuation");
+///
+/// let mut title = TitleCleaner::from_str("Lorem ipsum dolor: sit amet");
+/// title.clean_with(&pipeline);
+///
+/// assert_eq!(title.tokens(), &vec!["lorem", "ipsum", "dolor", "amet"]);
+/// # }
+/// ```
+#[derive(Default)]
+pub struct Pipeline {
+    steps: Vec<Box<dyn Step>>
+}
+
+impl Pipeline {
+    /// Get a shared preset, the preset is built the first time it's used on the current thread
+    pub(crate) fn preset<F: FnOnce() -> Pipeline>(preset: Preset, build: F) -> Rc<Pipeline> {
+        if let Some(pipeline) = PRESETS.with(|presets| presets.borrow().get(&preset).cloned()) {
+            return pipeline;
+        }
+
+        let pipeline = Rc::new(build());
+        PRESETS.with(|presets| presets.borrow_mut().insert(preset, Rc::clone(&pipeline)));
+
+        pipeline
+    }
+
+    /// Create a new empty Pipeline
+    pub fn new() -> Self {
+        Pipeline {
+            steps: Vec::new()
+        }
+    }
+
+    /// Pipeline used by the [TextCleaner](struct.TextCleaner.html)
+    ///
+    /// * tokens smaller or equal to `token_min_lenght` are removed
+    /// * HTML entities are decoded (html features)
+    /// * tokens are transformed to lowercase
+    /// * tokens are unidecoded
+    /// * non ASCII char are removed
+    /// * punctuation and digit are removed
+    /// * tokens are trimed
+    /// * empty tokens are removed
+    pub fn text(token_min_lenght: usize) -> Self {
+        let mut pipeline = Pipeline::new();
+
+        pipeline.add_step(MinLength(token_min_lenght));
+        pipeline.add_normalization_steps();
+        pipeline.add_step(RemoveEmpty);
+
+        pipeline
+    }
+
+    /// Pipeline used by the [TitleCleaner](struct.TitleCleaner.html)
+    ///
+    /// * subtitles are removed
+    /// * tokens between `(`, `)` and between `[`, `]` are removed
+    /// * then the [text pipeline](struct.Pipeline.html#method.text) is applied
+    pub fn title(token_min_lenght: usize) -> Self {
+        let mut pipeline = Pipeline::new();
+
+        pipeline.add_step(SplitAtStrongPunctuation);
+        pipeline.add_step(RemoveBetweenDelimiters::new("(", ")"));
+        pipeline.add_step(RemoveBetweenDelimiters::new("[", "]"));
+        pipeline.steps.append(&mut Pipeline::text(token_min_lenght).steps);
+
+        pipeline
+    }
+
+    /// Pipeline used by the [AuthorCleaner](struct.AuthorCleaner.html)
+    ///
+    /// * tokens between `(`, `)` and between `[`, `]` are removed
+    /// * HTML entities are decoded (html features)
+    /// * tokens are transformed to lowercase
+    /// * punctuation and digit are removed
+    /// * tokens are unidecoded
+    /// * non ASCII char are removed
+    /// * tokens are trimed
+    /// * empty tokens are removed
+    pub fn author() -> Self {
+        let mut pipeline = Pipeline::new();
+
+        pipeline.add_step(RemoveBetweenDelimiters::new("(", ")"));
+        pipeline.add_step(RemoveBetweenDelimiters::new("[", "]"));
+        pipeline.add_author_normalization_steps();
+        pipeline.add_step(RemoveEmpty);
+
+        pipeline
+    }
+
+    /// Pipeline used by the [CorporateCleaner](struct.CorporateCleaner.html), digits are kept (`Université Paris 7`)
+    ///
+    /// * HTML entities are decoded (html features)
+    /// * tokens are unidecoded, before the lowercase transformation so accented capitals (`École`) are transformed as well
+    /// * tokens are transformed to lowercase
+    /// * non ASCII char are removed
+    /// * punctuation is removed
+    /// * tokens are trimed
+    /// * empty tokens are removed
+    pub fn corporate() -> Self {
+        let mut pipeline = Pipeline::new();
+
+        #[cfg(feature = "html")]
+        pipeline.add_step(DecodeHtmlEntities);
+        pipeline.add_step(Unidecode);
+        pipeline.add_step(Lowercase);
+        pipeline.add_step(RemoveNonAscii);
+        pipeline.add_step(RemovePunctuation);
+        pipeline.add_step(Trim);
+        pipeline.add_step(RemoveEmpty);
+
+        pipeline
+    }
+
+    /// Pipeline used by the [PublisherCleaner](struct.PublisherCleaner.html)
+    ///
+    /// * the [corporate pipeline](struct.Pipeline.html#method.corporate) is applied
+    /// * hyphenated tokens are split (`Springer-Verlag`)
+    pub fn publisher() -> Self {
+        let mut pipeline = Pipeline::corporate();
+
+        pipeline.insert_step(pipeline.len() - 1, SplitAtHyphens);
+
+        pipeline
+    }
+
+    pub(crate) fn add_author_normalization_steps(&mut self) {
+        #[cfg(feature = "html")]
+        self.add_step(DecodeHtmlEntities);
+        self.add_step(Lowercase);
+        self.add_step(RemoveDigitAndPunctuation);
+        self.add_step(Unidecode);
+        self.add_step(RemoveNonAscii);
+        self.add_step(Trim);
+    }
+
+    pub(crate) fn add_normalization_steps(&mut self) {
+        #[cfg(feature = "html")]
+        self.add_step(DecodeHtmlEntities);
+        self.add_step(Lowercase);
+        self.add_step(Unidecode);
+        self.add_step(RemoveNonAscii);
+        self.add_step(RemoveDigitAndPunctuation);
+        self.add_step(Trim);
+    }
+
+    /// Applies a numeric policy to the text and title presets: digits are no longer removed along with punctuation and numbers are spared by the length threshold
+    pub(crate) fn set_numeric_policy(&mut self, policy: NumericPolicy, token_min_lenght: usize) {
+        if policy == NumericPolicy::Drop {
+            return;
+        }
+
+        if let Some(index) = self.position("min_length") {
+            self.steps[index] = Box::new(MinLengthExceptNumbers(token_min_lenght));
+        }
+
+        if let Some(index) = self.position("remove_digit_and_punctuation") {
+            self.steps[index] = Box::new(RemovePunctuation);
+            self.insert_step(index + 1, HandleNumbers(policy));
+        }
+    }
+
+    /// Removes stopwords before the empty tokens are removed, elided stopwords (`l'`, `dell'`) are split off before the length threshold and the punctuation removal
+    pub(crate) fn add_stopwords(&mut self, stopwords: &RemoveStopwords) {
+        let index = ["min_length", "min_length_except_numbers", "remove_digit_and_punctuation", "remove_punctuation"].iter()
+            .find_map(|name| self.position(name));
+
+        if let Some(index) = index {
+            self.insert_step(index, SplitElisions::new(stopwords));
+        }
+
+        match self.position("remove_empty") {
+            Some(index) => self.insert_step(index, stopwords.clone()),
+            None => self.add_step(stopwords.clone())
+        };
+    }
+
+    /// Add a step at the end of the pipeline
+    pub fn add_step<S: Step + 'static>(&mut self, step: S) -> &mut Self {
+        self.steps.push(Box::new(step));
+        self
+    }
+
+    /// Insert a step at a given position of the pipeline
+    ///
+    /// Panics if `index > len`
+    pub fn insert_step<S: Step + 'static>(&mut self, index: usize, step: S) -> &mut Self {
+        self.steps.insert(index, Box::new(step));
+        self
+    }
+
+    /// Remove all the steps named `name`
+    ///
+    /// ```
+    /// # use b_cleaner::Pipeline;
+    /// # fn main() {
+    /// let mut pipeline = Pipeline::title(3);
+    /// pipeline.remove_step("remove_between_delimiters");
+    ///
+    /// assert_eq!(pipeline.position("remove_between_delimiters"), None);
+    /// # }
+    /// ```
+    pub fn remove_step(&mut self, name: &str) -> &mut Self {
+        self.steps.retain(|step| step.name() != name);
+        self
+    }
+
+    /// Position of the first step named `name`
+    pub fn position(&self, name: &str) -> Option<usize> {
+        self.steps.iter().position(|step| step.name() == name)
+    }
+
+    /// Move the step at position `from` to position `to`
+    ///
+    /// Panics if `from` or `to` are out of bounds
+    pub fn move_step(&mut self, from: usize, to: usize) -> &mut Self {
+        let step = self.steps.remove(from);
+        self.steps.insert(to, step);
+        self
+    }
+
+    /// Names of the steps of the pipeline, in order
+    ///
+    /// ```
+    /// # use b_cleaner::Pipeline;
+    /// # fn main() {
+    /// let pipeline = Pipeline::text(3);
+    ///
+    /// assert_eq!(pipeline.step_names()[0], "min_length");
+    /// # }
+    /// ```
+    pub fn step_names(&self) -> Vec<&str> {
+        self.steps.iter().map(|step| step.name()).collect()
+    }
+
+    /// Number of steps of the pipeline
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Returns true if the pipeline has no step
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Apply all the steps of the pipeline to a list of tokens
+    pub fn apply<'a>(&self, tokens: &mut Vec<Cow<'a, str>>) {
+        self.steps.iter().for_each(|step| step.apply(tokens));
+        tokens.shrink_to_fit();
+    }
+
+    /// Apply all the steps of the pipeline except the steps named `name`
+    pub(crate) fn apply_skipping<'a>(&self, tokens: &mut Vec<Cow<'a, str>>, name: &str) {
+        self.steps.iter().filter(|step| step.name() != name).for_each(|step| step.apply(tokens));
+        tokens.shrink_to_fit();
+    }
+}
+
+impl fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.step_names()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets_match_cleaners() {
+        let tokens = ["Lorem", "ipsum", "(dolor)", "sit:", "[amet]", "consectetur", "adipiscing", "élit"];
+
+        let mut text = tokens.iter().map(|token| Cow::Borrowed(*token)).collect();
+        Pipeline::text(3).apply(&mut text);
+        assert_eq!(text, vec!["lorem", "ipsum", "dolor", "sit", "amet", "consectetur", "adipiscing", "elit"]);
+
+        let mut title = tokens.iter().map(|token| Cow::Borrowed(*token)).collect();
+        Pipeline::title(3).apply(&mut title);
+        assert_eq!(title, vec!["lorem", "ipsum"]);
+
+        let mut author = tokens.iter().map(|token| Cow::Borrowed(*token)).collect();
+        Pipeline::author().apply(&mut author);
+        assert_eq!(author, vec!["lorem", "ipsum", "sit", "consectetur", "adipiscing", "elit"]);
+    }
+
+    #[test]
+    fn test_move_step() {
+        let mut pipeline = Pipeline::new();
+        pipeline.add_step(Lowercase).add_step(Trim).add_step(RemoveEmpty);
+        pipeline.move_step(2, 0);
+
+        assert_eq!(pipeline.step_names(), vec!["remove_empty", "lowercase", "trim"]);
+    }
+
+    #[test]
+    fn test_presets_are_built_once() {
+        let first = Pipeline::preset(Preset::Author, Pipeline::author);
+        let second = Pipeline::preset(Preset::Author, || panic!("the preset must be reused"));
+
+        assert!(Rc::ptr_eq(&first, &second));
+        assert_ne!(Preset::Title(3, NumericPolicy::Drop, None), Preset::Title(2, NumericPolicy::Drop, None));
+    }
+}
@@ -0,0 +1,300 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::cleaners::Clean;
+use crate::pipeline::*;
+use crate::tokenizer::Tokenizer;
+
+#[cfg(feature = "stem")]
+use rust_stemmers::Algorithm;
+
+/// Separators of places in an imprint
+const SEPARATORS: &[&str] = &[";"];
+
+/// Markers of unknown places (`[S.l.]`, `sine loco`, `o.O.`), compared once normalized
+const UNKNOWN_PLACES: &[&str] = &[
+    "sl", "s l", "slnd", "sl nd", "sine loco", "oo", "o o", "ohne ort", "np", "n p", "no place", "sin lugar", "sd sl",
+    "lieu non identifie", "lieu de publication non identifie", "place of publication not identified"
+];
+
+/// Prepositions introducing a place (`A Paris`, `In London`, `Zu Berlin`), compared once normalized
+const PREPOSITIONS: &[&str] = &["a", "au", "aux", "en", "in", "at", "zu", "im", "apud", "chez"];
+
+/// Exonyms of common places of publication, the first form is the canonical one
+const EXONYMS: &[(&str, &[&str])] = &[
+    ("paris", &["parigi", "paryz", "parisiis", "lutetiae", "lutetiae parisiorum"]),
+    ("lyon", &["lyons", "lione", "lugduni"]),
+    ("strasbourg", &["strassburg", "strasburgo", "argentorati"]),
+    ("london", &["londres", "londra", "londen", "londini"]),
+    ("new york", &["new york ny", "nueva york", "nowy jork"]),
+    ("frankfurt am main", &["frankfurt", "frankfurt a m", "frankfurt am", "francfort", "francfort-sur-le-main", "francfort sur le main", "frankfort", "francofurti", "francofurti ad moenum"]),
+    ("munchen", &["muenchen", "munich", "monaco di baviera", "monachii"]),
+    ("koln", &["koeln", "cologne", "colonia", "coloniae"]),
+    ("nurnberg", &["nuernberg", "nuremberg", "norimberga", "norimbergae"]),
+    ("mainz", &["mayence", "magonza", "moguntiae"]),
+    ("aachen", &["aix-la-chapelle", "aquisgrana"]),
+    ("trier", &["treves", "treviri"]),
+    ("leipzig", &["lipsiae", "leipsic"]),
+    ("wien", &["vienne", "vienna", "viena", "vindobonae"]),
+    ("basel", &["bale", "basle", "basilea", "basileae"]),
+    ("zurich", &["zuerich", "zurigo", "tiguri"]),
+    ("geneve", &["genf", "geneva", "ginevra", "ginebra", "genevae"]),
+    ("bruxelles", &["brussels", "brussel", "bruselas", "bruxellae"]),
+    ("antwerpen", &["anvers", "antwerp", "amberes", "anversa", "antverpiae"]),
+    ("leuven", &["louvain", "lovaina", "lovanii"]),
+    ("gent", &["gand", "ghent"]),
+    ("brugge", &["bruges"]),
+    ("liege", &["luik", "luttich", "leodii"]),
+    ("den haag", &["la haye", "the hague", "la haya", "s-gravenhage", "hagae comitum"]),
+    ("roma", &["rome", "rom", "romae"]),
+    ("milano", &["milan", "mailand", "mediolani"]),
+    ("venezia", &["venise", "venice", "venecia", "venedig", "venetiis"]),
+    ("firenze", &["florence", "florenz", "florencia", "florentiae"]),
+    ("napoli", &["naples", "neapel", "napoles", "neapoli"]),
+    ("torino", &["turin", "augustae taurinorum"]),
+    ("genova", &["genes", "genoa", "genua"]),
+    ("sevilla", &["seville", "hispali"]),
+    ("lisboa", &["lisbonne", "lisbon", "lissabon", "olisipone"]),
+    ("kobenhavn", &["copenhague", "copenhagen", "kopenhagen", "copenaghen", "hafniae"]),
+    ("praha", &["prague", "prag", "praga", "pragae"]),
+    ("warszawa", &["varsovie", "warsaw", "warschau", "varsovia"]),
+    ("krakow", &["cracovie", "cracow", "krakau", "cracoviae"]),
+    ("wroclaw", &["breslau"]),
+    ("gdansk", &["danzig", "dantzig"]),
+    ("bratislava", &["presbourg", "pressburg", "pozsony"]),
+    ("ljubljana", &["laibach"]),
+    ("athina", &["athenes", "athens", "athen", "atene", "atenas"]),
+    ("moskva", &["moscou", "moscow", "moskau", "moscu", "mosca"]),
+    ("sankt-peterburg", &["saint-petersbourg", "saint petersbourg", "st petersburg", "saint petersburg", "sankt petersburg", "san petersburgo"]),
+    ("kyiv", &["kiev", "kiew", "kijow"]),
+    ("beijing", &["pekin", "peking"]),
+    ("mexico", &["ciudad de mexico", "mexico df"])
+];
+
+/// Normalizes a place with the [corporate pipeline](struct.Pipeline.html#method.corporate), words are joined by a white space
+pub(crate) fn normalize_place(place: &str) -> String {
+    let mut tokens : Vec<Cow<str>> = Tokenizer::new(place).map(Cow::Borrowed).collect();
+    Pipeline::preset(Preset::Corporate, Pipeline::corporate).apply(&mut tokens);
+
+    tokens.join(" ")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A list of places and their variants (exonyms, Latin forms), used to map variants to a canonical form
+///
+/// The default gazetteer contains the exonyms of common places of publication, it's bundled with the library and doesn't need any network access.
+///
+/// ```
+/// # use b_cleaner::Gazetteer;
+/// # fn main() {
+/// let mut gazetteer = Gazetteer::default();
+///
+/// assert_eq!(gazetteer.canonical("Francfort-sur-le-Main"), Some("frankfurt am main"));
+/// assert_eq!(gazetteer.canonical("Vienna"), Some("wien"));
+/// assert_eq!(gazetteer.canonical("Sceaux"), None);
+///
+/// gazetteer.add_place("sceaux", &["sceaux (hauts-de-seine)"]);
+/// assert_eq!(gazetteer.canonical("Sceaux (Hauts-de-Seine)"), Some("sceaux"));
+/// # }
+/// ```
+pub struct Gazetteer {
+    places: HashMap<String, String>
+}
+
+impl Gazetteer {
+    /// Create a new empty Gazetteer
+    pub fn new() -> Self {
+        Gazetteer {
+            places: HashMap::new()
+        }
+    }
+
+    /// Add a place and its variants, the place and its variants are normalized
+    pub fn add_place<S: AsRef<str>>(&mut self, canonical: &str, variants: &[S]) -> &mut Self {
+        let canonical = normalize_place(canonical);
+
+        variants.iter().for_each(|variant| {
+            self.places.insert(normalize_place(variant.as_ref()), canonical.clone());
+        });
+        self.places.insert(canonical.clone(), canonical);
+
+        self
+    }
+
+    /// Get the canonical form of a place, the place is normalized before the lookup
+    pub fn canonical(&self, place: &str) -> Option<&str> {
+        self.places.get(&normalize_place(place)).map(String::as_str)
+    }
+}
+
+impl Default for Gazetteer {
+    fn default() -> Self {
+        let mut gazetteer = Gazetteer::new();
+
+        EXONYMS.iter().for_each(|(canonical, variants)| {
+            gazetteer.add_place(canonical, variants);
+        });
+
+        gazetteer
+    }
+}
+
+#[derive(Debug, Clone)]
+/// A struct dedicated to places of publication cleaning
+///
+/// Places are split on `;` (`London ; New York`) and cleaned by the [corporate pipeline](struct.Pipeline.html#method.corporate). Prepositions introducing a place (`A Paris`) are removed, as well as markers of unknown places (`[S.l.]`, `sine loco`).
+///
+/// Exonyms can be mapped to a canonical form with a [Gazetteer](struct.Gazetteer.html), see [map_exonyms](#method.map_exonyms).
+///
+/// ```
+/// # use b_cleaner::{PlaceCleaner, Clean};
+/// # fn main() {
+/// let mut place = PlaceCleaner::from_str("A Paris ; [S.l.] ; London ; New York");
+///
+/// place.clean();
+///
+/// assert_eq!(place.keys(), vec!["paris", "london", "new york"]);
+/// # }
+/// ```
+pub struct PlaceCleaner<'a> {
+    places: Vec<Vec<Cow<'a, str>>>,
+    gazetteer: Option<Gazetteer>
+}
+
+impl <'a>PlaceCleaner<'a> {
+    /// Create a new PlaceCleaner
+    ///
+    /// ```
+    /// # use b_cleaner::PlaceCleaner;
+    /// # fn main() {
+    /// let tokens = vec!["Paris", ";", "Bruxelles"];
+    /// let place = PlaceCleaner::new(&tokens);
+    ///
+    /// assert_eq!(place.places(), &vec![vec!["Paris"], vec!["Bruxelles"]]);
+    /// # }
+    /// ```
+    pub fn new<R: AsRef<str>>(input: &'a [R]) -> Self {
+        PlaceCleaner::with_tokens(input.iter().map(|token| Cow::Borrowed(token.as_ref())))
+    }
+
+    /// Create a new PlaceCleaner from a raw string, the string is tokenized with the [Tokenizer](struct.Tokenizer.html)
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(input: &'a str) -> Self {
+        PlaceCleaner::with_tokens(Tokenizer::new(input).map(Cow::Borrowed))
+    }
+
+    fn with_tokens<I: Iterator<Item = Cow<'a, str>>>(tokens: I) -> Self {
+        let mut places = vec![Vec::new()];
+
+        for token in tokens {
+            if SEPARATORS.contains(&token.as_ref()) {
+                places.push(Vec::new());
+            } else {
+                places.last_mut().expect("places can't be empty").push(token);
+            }
+        }
+
+        places.retain(|place| !place.is_empty());
+
+        PlaceCleaner {
+            places,
+            gazetteer: None
+        }
+    }
+
+    /// Get the places
+    pub fn places(&self) -> &Vec<Vec<Cow<'a, str>>> {
+        &self.places
+    }
+
+    /// Map exonyms to their canonical form with the bundled [Gazetteer](struct.Gazetteer.html) while cleaning
+    ///
+    /// ```
+    /// # use b_cleaner::{PlaceCleaner, Clean};
+    /// # fn main() {
+    /// let mut place = PlaceCleaner::from_str("Francfort");
+    /// place.map_exonyms(true);
+    ///
+    /// place.clean();
+    ///
+    /// assert_eq!(place.keys(), PlaceCleaner::from_str("Frankfurt am Main").clean().keys());
+    /// # }
+    /// ```
+    pub fn map_exonyms(&mut self, map: bool) -> &Self {
+        self.gazetteer = match map {
+            true => Some(Gazetteer::default()),
+            false => None
+        };
+
+        self
+    }
+
+    /// Map places to their canonical form with a user supplied [Gazetteer](struct.Gazetteer.html) while cleaning
+    pub fn gazetteer(&mut self, gazetteer: Gazetteer) -> &Self {
+        self.gazetteer = Some(gazetteer);
+        self
+    }
+
+    /// Clean each place with a custom [Pipeline](struct.Pipeline.html) instead of the preset one
+    pub fn clean_with(&mut self, pipeline: &Pipeline) -> &Self {
+        for place in self.places.iter_mut() {
+            pipeline.apply(place);
+
+            while place.len() > 1 && PREPOSITIONS.contains(&place[0].as_ref()) {
+                place.remove(0);
+            }
+
+            if UNKNOWN_PLACES.contains(&place.join(" ").as_str()) {
+                place.clear();
+            }
+
+            if let Some(canonical) = self.gazetteer.as_ref().and_then(|gazetteer| gazetteer.places.get(&place.join(" "))) {
+                *place = canonical.split(' ').map(|word| Cow::Owned(word.to_string())).collect();
+            }
+        }
+
+        self.places.retain(|place| !place.is_empty());
+
+        self
+    }
+
+    /// Build a key for each place
+    pub fn keys(&self) -> Vec<String> {
+        self.places.iter().map(|place| place.join(" ")).collect()
+    }
+}
+
+impl <'a>Clean for PlaceCleaner<'a> {
+    fn clean(&mut self) -> &Self {
+        self.clean_with(&Pipeline::preset(Preset::Corporate, Pipeline::corporate))
+    }
+
+    /// Place names are not stemmed
+    #[cfg(feature = "stem")]
+    fn stem(&mut self, _lang: Algorithm) -> &Self {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(input: &str, map_exonyms: bool) -> Vec<String> {
+        let mut place = PlaceCleaner::from_str(input);
+        place.map_exonyms(map_exonyms);
+        place.clean();
+
+        place.keys()
+    }
+
+    #[test]
+    fn test_places() {
+        assert_eq!(keys("[S.l.]", false), Vec::<String>::new());
+        assert_eq!(keys("Sine loco", false), Vec::<String>::new());
+        assert_eq!(keys("In London ; Zu Berlin", false), vec!["london", "berlin"]);
+        assert_eq!(keys("Frankfurt am Main", false), vec!["frankfurt am main"]);
+        assert_eq!(keys("New York, N.Y.", true), vec!["new york"]);
+        assert_eq!(keys("Lugduni ; Londres", true), vec!["lyon", "london"]);
+    }
+}
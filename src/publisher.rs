@@ -0,0 +1,314 @@
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use crate::cleaners::Clean;
+use crate::language::Language;
+use crate::pipeline::*;
+use crate::stopwords::{RemoveStopwords, normalize_word};
+use crate::tokenizer::{Tokenizer, is_punctuation};
+
+#[cfg(feature = "stem")]
+use rust_stemmers::Algorithm;
+
+/// Legal forms of companies, compared once normalized (`S.A.` becomes `sa`)
+const LEGAL_FORMS: &[&str] = &[
+    "gmbh", "mbh", "ag", "kg", "kgaa", "ohg", "ug", "co", "cie", "company", "inc", "incorporated", "ltd", "limited", "llc", "plc",
+    "corp", "corporation", "sa", "sas", "sarl", "eurl", "snc", "spa", "srl", "sl", "bv", "nv", "ab", "oy", "ltee", "pty"
+];
+
+/// Generic words of publisher names, compared once normalized
+const GENERIC_WORDS: &[&str] = &[
+    "editions", "edition", "ed", "eds", "editeur", "editeurs", "editore", "editori", "edizioni", "ediciones", "editorial", "editora",
+    "verlag", "verlage", "verlagsgesellschaft", "buchverlag", "publishing", "publisher", "publishers", "publication", "publications",
+    "books", "press", "presses", "librairie", "libreria", "imprimerie", "impr", "group", "groupe", "gruppe"
+];
+
+/// Common acronyms of publishers and their expansion
+const ACRONYMS: &[(&str, &str)] = &[
+    ("puf", "presses universitaires de france"),
+    ("pur", "presses universitaires de rennes"),
+    ("pul", "presses universitaires de lyon"),
+    ("pum", "presses universitaires du mirail"),
+    ("ppur", "presses polytechniques et universitaires romandes"),
+    ("lgf", "librairie generale francaise"),
+    ("oup", "oxford university press"),
+    ("cup", "cambridge university press")
+];
+
+/// Expands the bundled acronyms written in uppercase (`PUF`) or making the whole name (`Puf`), so that words such as `cup` are left as is
+#[derive(Debug, Clone)]
+struct ExpandAcronyms(ReplaceWords);
+
+impl Step for ExpandAcronyms {
+    fn name(&self) -> &str {
+        "expand_acronyms"
+    }
+
+    fn apply<'a>(&self, tokens: &mut Vec<Cow<'a, str>>) {
+        let whole_name = tokens.iter().filter(|token| !token.chars().all(is_punctuation)).count() == 1;
+
+        *tokens = tokens.drain(..).flat_map(|token| {
+            let uppercase = token.chars().filter(|c| c.is_alphabetic()).count() > 1 && !token.chars().any(char::is_lowercase);
+            let mut acronym = vec![Cow::Owned(normalize_word(&token.replace('.', "")))];
+
+            if (whole_name || uppercase) && self.0.contains(&acronym[0]) {
+                self.0.apply(&mut acronym);
+                acronym
+            } else {
+                vec![token]
+            }
+        }).collect();
+    }
+}
+
+/// Removes the legal forms, generic words and stopwords of a name, the name is left untouched rather than removed entirely (`Press`)
+///
+/// Stopwords are removed at the start and at the end of the name (`The MIT Press`, `Little, Brown and Co.`), or everywhere once [stopwords](struct.PublisherCleaner.html#method.stopwords) are set.
+#[derive(Debug, Clone)]
+struct RemoveNameWords {
+    legal_forms: RemoveStopwords,
+    generic_words: RemoveStopwords,
+    stopwords: Option<RemoveStopwords>,
+    edge_stopwords: RemoveStopwords
+}
+
+impl Step for RemoveNameWords {
+    fn name(&self) -> &str {
+        "remove_name_words"
+    }
+
+    fn apply<'a>(&self, tokens: &mut Vec<Cow<'a, str>>) {
+        let name = tokens.clone();
+
+        self.legal_forms.apply(tokens);
+        self.generic_words.apply(tokens);
+
+        if let Some(stopwords) = &self.stopwords {
+            stopwords.apply(tokens);
+        }
+
+        // The last word is kept even if it's a stopword (`mit` in `The MIT Press`)
+        while tokens.len() > 1 && tokens.first().is_some_and(|token| self.edge_stopwords.contains(token)) {
+            tokens.remove(0);
+        }
+
+        while tokens.len() > 1 && tokens.last().is_some_and(|token| self.edge_stopwords.contains(token)) {
+            tokens.pop();
+        }
+
+        if tokens.is_empty() {
+            *tokens = name;
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+/// A struct dedicated to publisher names cleaning
+///
+/// Publisher names are cleaned by the [publisher pipeline](struct.Pipeline.html#method.publisher), then acronyms and aliases are expanded (`PUF` becomes `presses universitaires de france`), and legal forms (`GmbH`, `Inc.`, `S.A.`), generic words (`Éditions`, `Verlag`, `Publishing`) and the stopwords starting or ending the name are removed. A name made only of such words is kept whole (`Press`).
+///
+/// Bundled acronyms are only expanded when written in uppercase or making the whole name, `World Cup Publishing` is left as is.
+///
+/// ```
+/// # use b_cleaner::{PublisherCleaner, Clean};
+/// # fn main() {
+/// let mut publisher = PublisherCleaner::from_str("Springer-Verlag GmbH");
+///
+/// publisher.clean();
+///
+/// assert_eq!(publisher.tokens(), &vec!["springer"]);
+/// assert_eq!(PublisherCleaner::from_str("Éditions Gallimard").clean().key(), PublisherCleaner::from_str("Gallimard").clean().key());
+/// assert_eq!(PublisherCleaner::from_str("PUF").clean().key(), PublisherCleaner::from_str("Presses universitaires de France").clean().key());
+/// # }
+/// ```
+pub struct PublisherCleaner<'a> {
+    tokens: Vec<Cow<'a, str>>,
+    aliases: ReplaceWords,
+    acronyms: ReplaceWords,
+    legal_forms: RemoveStopwords,
+    generic_words: RemoveStopwords,
+    stopwords: Option<RemoveStopwords>,
+    customized: bool
+}
+
+impl <'a>PublisherCleaner<'a> {
+    /// Create a new PublisherCleaner
+    ///
+    /// ```
+    /// # use b_cleaner::{PublisherCleaner, Clean};
+    /// # fn main() {
+    /// let tokens = vec!["Little,", "Brown", "and", "Co."];
+    /// let mut publisher = PublisherCleaner::new(&tokens);
+    ///
+    /// publisher.clean();
+    ///
+    /// assert_eq!(publisher.tokens(), &vec!["little", "brown"]);
+    /// # }
+    /// ```
+    pub fn new<R: AsRef<str>>(input: &'a [R]) -> Self {
+        PublisherCleaner::with_tokens(input.iter().map(|token| Cow::Borrowed(token.as_ref())))
+    }
+
+    /// Create a new PublisherCleaner from a raw string, the string is tokenized with the [Tokenizer](struct.Tokenizer.html)
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(input: &'a str) -> Self {
+        PublisherCleaner::with_tokens(Tokenizer::new(input).map(Cow::Borrowed))
+    }
+
+    fn with_tokens<I: Iterator<Item = Cow<'a, str>>>(tokens: I) -> Self {
+        let mut acronyms = ReplaceWords::new();
+
+        ACRONYMS.iter().for_each(|(acronym, name)| {
+            acronyms.add_word(acronym, name);
+        });
+
+        PublisherCleaner {
+            tokens: tokens.collect(),
+            aliases: ReplaceWords::new(),
+            acronyms,
+            legal_forms: RemoveStopwords::from_words(LEGAL_FORMS),
+            generic_words: RemoveStopwords::from_words(GENERIC_WORDS),
+            stopwords: None,
+            customized: false
+        }
+    }
+
+    /// Get the tokens
+    pub fn tokens(&self) -> &Vec<Cow<'a, str>> {
+        &self.tokens
+    }
+
+    /// Add an alias of a publisher, the alias is replaced by the name while cleaning whatever its case
+    ///
+    /// ```
+    /// # use b_cleaner::{PublisherCleaner, Clean};
+    /// # fn main() {
+    /// let mut publisher = PublisherCleaner::from_str("CNRS Éd.");
+    /// publisher.add_alias("cnrs", "centre national de la recherche scientifique");
+    ///
+    /// publisher.clean();
+    ///
+    /// assert_eq!(publisher.key(), "centre national de la recherche scientifique");
+    /// # }
+    /// ```
+    pub fn add_alias(&mut self, alias: &str, name: &str) -> &Self {
+        self.aliases.add_word(alias, name);
+        self.customized = true;
+        self
+    }
+
+    /// Add user supplied legal forms, legal forms are compared once normalized (`S.A.` must be given as `sa`)
+    pub fn add_legal_forms<S: AsRef<str>>(&mut self, legal_forms: &[S]) -> &Self {
+        self.legal_forms.add_words(legal_forms);
+        self.customized = true;
+        self
+    }
+
+    /// Add user supplied generic words
+    pub fn add_generic_words<S: AsRef<str>>(&mut self, words: &[S]) -> &Self {
+        self.generic_words.add_words(words);
+        self.customized = true;
+        self
+    }
+
+    /// Remove the stopwords of some languages everywhere in the name, only the stopwords starting or ending the name are removed by default
+    ///
+    /// ```
+    /// # use b_cleaner::{PublisherCleaner, Language, Clean};
+    /// # fn main() {
+    /// let mut publisher = PublisherCleaner::from_str("Éditions de la Maison des sciences de l'homme");
+    /// assert_eq!(publisher.clean().key(), "maison des sciences de lhomme");
+    ///
+    /// let mut publisher = PublisherCleaner::from_str("Éditions de la Maison des sciences de l'homme");
+    /// publisher.stopwords(&[Language::French]);
+    /// assert_eq!(publisher.clean().key(), "maison sciences lhomme");
+    /// # }
+    /// ```
+    pub fn stopwords(&mut self, languages: &[Language]) -> &Self {
+        self.stopwords = Some(RemoveStopwords::new(languages));
+        self.customized = true;
+        self
+    }
+
+    /// Get the pipeline used to clean the name, bundled acronyms are expanded before the lowercase transformation, then aliases are expanded and legal forms, generic words and stopwords are removed after the normalization steps
+    pub fn pipeline(&self) -> Pipeline {
+        let mut pipeline = Pipeline::publisher();
+
+        if let Some(index) = pipeline.position("lowercase") {
+            pipeline.insert_step(index, ExpandAcronyms(self.acronyms.clone()));
+        }
+
+        pipeline.insert_step(pipeline.len() - 1, self.aliases.clone());
+        pipeline.insert_step(pipeline.len() - 1, RemoveNameWords {
+            legal_forms: self.legal_forms.clone(),
+            generic_words: self.generic_words.clone(),
+            stopwords: self.stopwords.clone(),
+            edge_stopwords: RemoveStopwords::new(&Language::ALL)
+        });
+
+        pipeline
+    }
+
+    /// Clean the name with a custom [Pipeline](struct.Pipeline.html) instead of the preset one
+    pub fn clean_with(&mut self, pipeline: &Pipeline) -> &Self {
+        pipeline.apply(&mut self.tokens);
+        self
+    }
+
+    /// Build a key out of the name
+    pub fn key(&self) -> String {
+        self.tokens.join(" ")
+    }
+}
+
+impl <'a>Clean for PublisherCleaner<'a> {
+    fn clean(&mut self) -> &Self {
+        let pipeline = if self.customized {
+            Rc::new(self.pipeline())
+        } else {
+            Pipeline::preset(Preset::PublisherCleaner, || self.pipeline())
+        };
+
+        self.clean_with(&pipeline)
+    }
+
+    #[cfg(feature = "stem")]
+    fn stem(&mut self, lang: Algorithm) -> &Self {
+        Stem::new(lang).apply(&mut self.tokens);
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(input: &str) -> String {
+        PublisherCleaner::from_str(input).clean().key()
+    }
+
+    #[test]
+    fn test_publisher_keys() {
+        assert_eq!(key("Éditions du Seuil"), "seuil");
+        assert_eq!(key("Oxford University Press"), key("OUP"));
+        assert_eq!(key("Gallimard S.A."), "gallimard");
+        assert_eq!(key("Wiley Publishing, Inc."), "wiley");
+        assert_eq!(key("Verlag C.H. Beck oHG"), "ch beck");
+    }
+
+    #[test]
+    fn test_publisher_names_are_never_emptied() {
+        assert_eq!(key("The MIT Press"), "mit");
+        assert_eq!(key("Press"), "press");
+        assert_eq!(key("Presses de la Cité"), "cite");
+    }
+
+    #[test]
+    fn test_acronyms() {
+        assert_eq!(key("World Cup Publishing"), "world cup");
+        assert_eq!(key("CUP"), key("Cambridge University Press"));
+        assert_eq!(key("Puf"), key("Presses universitaires de France"));
+        assert_eq!(key("P.U.F."), key("PUF"));
+    }
+}
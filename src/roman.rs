@@ -0,0 +1,136 @@
+use crate::meeting::ORDINAL_SUFFIXES;
+
+/// Values of Roman numerals, subtractive pairs included, from the highest to the lowest
+const NUMERALS: &[(&str, u32)] = &[
+    ("M", 1000), ("CM", 900), ("D", 500), ("CD", 400),
+    ("C", 100), ("XC", 90), ("L", 50), ("XL", 40),
+    ("X", 10), ("IX", 9), ("V", 5), ("IV", 4), ("I", 1)
+];
+
+/// Largest number written with Roman numerals
+const MAX_ROMAN: u32 = 3999;
+
+/// Words and acronyms written in uppercase which are also valid Roman numerals
+const FALSE_FRIENDS: &[&str] = &["CC", "CD", "CI", "CL", "CM", "CV", "DC", "DI", "DIV", "DIX", "DM", "LI", "LIV", "MC", "MD", "MI", "MIX", "MM", "XL"];
+
+/// Single numerals recognized when followed by a French or Italian ordinal suffix (`Ve`, `Xe`, `Ier`, `Vº`), other single numerals are words (`Le`, `Ce`, `De`)
+const SINGLE_NUMERALS: &[char] = &['I', 'V', 'X'];
+
+/// Ordinal suffixes accepted after a single numeral, `o` and `a` are left out (`Io`)
+const SINGLE_NUMERAL_SUFFIXES: &[&str] = &["e", "er", "re", "eme", "ème", "º", "ª"];
+
+/// Writes a number with Roman numerals in uppercase, returns None for 0 and numbers above 3999
+pub(crate) fn to_roman(mut number: u32) -> Option<String> {
+    if number == 0 || number > MAX_ROMAN {
+        return None;
+    }
+
+    let mut roman = String::new();
+
+    for (numeral, value) in NUMERALS {
+        while number >= *value {
+            roman.push_str(numeral);
+            number -= value;
+        }
+    }
+
+    Some(roman)
+}
+
+/// Parses Roman numerals, case insensitive
+///
+/// Only numerals in their canonical form are accepted (`IV` but not `IIII`, `XC` but not `LXL`), so most words made of Roman letters (`did`, `mill`, `vivid`) are rejected.
+pub(crate) fn parse_roman(input: &str) -> Option<u32> {
+    let input = input.to_ascii_uppercase();
+    let mut rest = input.as_str();
+    let mut number = 0;
+
+    for (numeral, value) in NUMERALS {
+        while let Some(tail) = rest.strip_prefix(numeral) {
+            number += value;
+            rest = tail;
+        }
+    }
+
+    if !rest.is_empty() || number == 0 {
+        return None;
+    }
+
+    to_roman(number).filter(|canonical| *canonical == input).map(|_| number)
+}
+
+/// Returns true if a single numeral followed by a suffix is a word (`Le`, `DE`, `CE`) rather than an ordinal (`Ve`, `IER`)
+fn is_single_numeral_word(roman: &str, suffix: &str) -> bool {
+    !(SINGLE_NUMERALS.iter().any(|numeral| roman.starts_with(*numeral)) && SINGLE_NUMERAL_SUFFIXES.contains(&suffix))
+}
+
+/// Parses Roman numerals written in uppercase and followed by an optional ordinal suffix (`XIXe`, `IIIème`, `IV`)
+///
+/// Lowercase numerals are rejected, most of them are words (`de`, `mix`, `vi`). A single numeral followed by a suffix is rejected unless it's an ordinal (`LE`, `DE` but not `VE`).
+pub(crate) fn parse_roman_ordinal(input: &str) -> Option<u32> {
+    let numerals = input.chars().take_while(|c| "IVXLCDM".contains(*c)).count();
+    let suffix = input[numerals..].to_lowercase();
+
+    if numerals == 0 || !suffix.is_empty() && !ORDINAL_SUFFIXES.contains(&suffix.as_str()) {
+        return None;
+    }
+
+    if numerals == 1 && !suffix.is_empty() && is_single_numeral_word(&input[..numerals], &suffix) {
+        return None;
+    }
+
+    parse_roman(&input[..numerals])
+}
+
+/// Recognizes a Roman numeral inside a title or a text, returns its value and its ordinal suffix (`XIXe`, `Louis XIV`)
+///
+/// Numerals must be written in uppercase and their suffix in lowercase, so words (`mix`, `did`, `Le`, `DE`) are rejected. Single numerals without suffix (`I, Robot`, `Malcolm X`) and acronyms (`CD`, `XL`) are rejected as well.
+pub(crate) fn recognize_roman(token: &str) -> Option<(u32, &str)> {
+    let numerals = token.chars().take_while(|c| "IVXLCDM".contains(*c)).count();
+    let (roman, suffix) = token.split_at(numerals);
+
+    if numerals == 0 || FALSE_FRIENDS.contains(&roman) || !suffix.is_empty() && !ORDINAL_SUFFIXES.contains(&suffix) {
+        return None;
+    }
+
+    if numerals == 1 && is_single_numeral_word(roman, suffix) {
+        return None;
+    }
+
+    parse_roman(roman).map(|number| (number, suffix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roman_numerals() {
+        assert_eq!(parse_roman("MCMLXXX"), Some(1980));
+        assert_eq!(parse_roman("xiv"), Some(14));
+        assert_eq!(parse_roman("IIII"), None);
+        assert_eq!(parse_roman("did"), None);
+        assert_eq!(parse_roman(""), None);
+        assert_eq!(to_roman(1789).as_deref(), Some("MDCCLXXXIX"));
+        assert_eq!(parse_roman_ordinal("XIXe"), Some(19));
+        assert_eq!(parse_roman_ordinal("de"), None);
+        assert_eq!(parse_roman_ordinal("LE"), None);
+        assert_eq!(parse_roman_ordinal("VE"), Some(5));
+    }
+
+    #[test]
+    fn test_recognize_roman() {
+        assert_eq!(recognize_roman("XIV"), Some((14, "")));
+        assert_eq!(recognize_roman("XIXe"), Some((19, "e")));
+        assert_eq!(recognize_roman("Ier"), Some((1, "er")));
+        assert_eq!(recognize_roman("IIº"), Some((2, "º")));
+        assert_eq!(recognize_roman("mix"), None);
+        assert_eq!(recognize_roman("MIX"), None);
+        assert_eq!(recognize_roman("did"), None);
+        assert_eq!(recognize_roman("Le"), None);
+        assert_eq!(recognize_roman("DE"), None);
+        assert_eq!(recognize_roman("Io"), None);
+        assert_eq!(recognize_roman("I"), None);
+        assert_eq!(recognize_roman("Livre"), None);
+    }
+}
@@ -0,0 +1,237 @@
+use std::borrow::Cow;
+
+use crate::cleaners::{Clean, TitleCleaner};
+use crate::identifiers::{Identifier, IdentifierCleaner, IdentifierKind};
+use crate::language::Language;
+use crate::numbering::{Designation, Numbering, parse_number, split_off_numbering};
+use crate::pipeline::*;
+use crate::tokenizer::{Tokenizer, is_punctuation};
+
+#[cfg(feature = "stem")]
+use rust_stemmers::Algorithm;
+
+/// Separator between the parts of a series statement (`Que sais-je ? ; 1234`)
+const SEPARATOR: &str = ";";
+
+/// Removes the parentheses enclosing a series statement, attached (`(Que`) or not
+fn trim_parentheses<'a>(token: Cow<'a, str>, start: bool, end: bool) -> Cow<'a, str> {
+    fn trim(token: &str, start: bool, end: bool) -> &str {
+        let token = if start { token.trim_start_matches('(') } else { token };
+
+        if end { token.trim_end_matches(')') } else { token }
+    }
+
+    match token {
+        Cow::Borrowed(token) => Cow::Borrowed(trim(token, start, end)),
+        Cow::Owned(token) => Cow::Owned(trim(&token, start, end).to_string())
+    }
+}
+
+/// Returns true if the token is an ISSN (`ISSN:0302-9743`, `0302-9743`), unlabelled ISSN must be valid
+fn is_issn(token: &str) -> bool {
+    IdentifierCleaner::new(&[token]).candidates().iter()
+        .any(|(kind, candidate)| *kind == IdentifierKind::Issn && Identifier::parse(*kind, candidate).is_ok())
+}
+
+#[derive(Debug, Clone)]
+/// A struct dedicated to series statements cleaning
+///
+/// Series statements are split into the title of the series, its ISSN and the numbering within the series. The enclosing parentheses are removed, the parts of the statement are separated by `;` and the ISSN is taken from its label (`ISSN 0302-9743`) or from its shape when it is a valid ISSN (`0302-9743`), ranges of years (`1900-1908`) are left in the title.
+///
+/// The title of the series is cleaned with the [TitleCleaner](struct.TitleCleaner.html) rules, numbers are read as in [TitleCleaner::numbering](struct.TitleCleaner.html#method.numbering), a bare number following `;` (`; 1234`) is an unspecified designation.
+///
+/// ```
+/// # use b_cleaner::{SeriesCleaner, Clean, Designation, Identifier, Numbering};
+/// # fn main() {
+/// let mut series = SeriesCleaner::from_str("Lecture notes in computer science, ISSN 0302-9743 ; v. 1000");
+///
+/// series.clean();
+///
+/// assert_eq!(series.tokens(), &vec!["lecture", "notes", "computer", "science"]);
+/// assert_eq!(series.issn(), Some(&Identifier::Issn("0302-9743".to_string())));
+/// assert_eq!(series.numbering(), &vec![Numbering::new(Designation::Volume, 1000)]);
+///
+/// let mut series = SeriesCleaner::from_str("(Que sais-je ? ; 1234)");
+///
+/// series.clean();
+///
+/// assert_eq!(series.numbering(), &vec![Numbering::new(Designation::Unspecified, 1234)]);
+/// assert!(series.issn().is_none());
+/// # }
+/// ```
+pub struct SeriesCleaner<'a> {
+    title: TitleCleaner<'a>,
+    issn_tokens: Vec<Cow<'a, str>>,
+    numbering_tokens: Vec<Vec<Cow<'a, str>>>,
+    issn: Option<Identifier>,
+    numbering: Vec<Numbering>
+}
+
+impl <'a>SeriesCleaner<'a> {
+    /// Create a new SeriesCleaner
+    ///
+    /// ```
+    /// # use b_cleaner::{SeriesCleaner, Clean};
+    /// # fn main() {
+    /// let tokens = vec!["(Bibliothèque", "de", "la", "Pléiade", ";", "123)"];
+    /// let mut series = SeriesCleaner::new(&tokens);
+    ///
+    /// series.clean();
+    ///
+    /// assert_eq!(series.key(), "bibliotheque pleiade");
+    /// assert_eq!(series.numbering()[0].number(), 123);
+    /// # }
+    /// ```
+    pub fn new<R: AsRef<str>>(input: &'a [R]) -> Self {
+        SeriesCleaner::with_tokens(input.iter().map(|token| Cow::Borrowed(token.as_ref())))
+    }
+
+    /// Create a new SeriesCleaner from a raw string, the string is tokenized with the [Tokenizer](struct.Tokenizer.html)
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(input: &'a str) -> Self {
+        SeriesCleaner::with_tokens(Tokenizer::new(input).map(Cow::Borrowed))
+    }
+
+    fn with_tokens<I: Iterator<Item = Cow<'a, str>>>(tokens: I) -> Self {
+        let tokens : Vec<Cow<'a, str>> = tokens.collect();
+        let last = tokens.len().saturating_sub(1);
+        let enclosed = tokens.first().is_some_and(|token| token.starts_with('(')) && tokens.last().is_some_and(|token| token.ends_with(')'));
+
+        let tokens = tokens.into_iter()
+            .enumerate()
+            .map(|(index, token)| trim_parentheses(token, enclosed && index == 0, enclosed && index == last))
+            .filter(|token| !token.is_empty());
+
+        let mut parts : Vec<Vec<Cow<'a, str>>> = vec![Vec::new()];
+        let mut issn_tokens = Vec::new();
+        let mut label = false;
+
+        // The token following the `ISSN` label is taken as is, so that invalid ISSN don't end up in the title
+        for token in tokens {
+            if token == SEPARATOR {
+                parts.push(Vec::new());
+                label = false;
+            } else if token.eq_ignore_ascii_case("issn") {
+                issn_tokens.push(token);
+                label = true;
+            } else if label || is_issn(&token) {
+                label = label && token.chars().all(is_punctuation);
+                issn_tokens.push(token);
+            } else if let Some(part) = parts.last_mut() {
+                part.push(token);
+            }
+        }
+
+        let title = parts.remove(0);
+
+        SeriesCleaner {
+            title: TitleCleaner::with_tokens(title),
+            issn_tokens,
+            numbering_tokens: parts,
+            issn: None,
+            numbering: Vec::new()
+        }
+    }
+
+    /// Get the tokens of the title of the series
+    pub fn tokens(&self) -> &Vec<Cow<'a, str>> {
+        self.title.tokens()
+    }
+
+    /// Get the ISSN of the series, None if the statement has no valid ISSN or before cleaning
+    pub fn issn(&self) -> Option<&Identifier> {
+        self.issn.as_ref()
+    }
+
+    /// Get the numbering within the series, empty before cleaning
+    pub fn numbering(&self) -> &Vec<Numbering> {
+        &self.numbering
+    }
+
+    /// Remove the stopwords of some languages from the title of the series, see [RemoveStopwords](struct.RemoveStopwords.html)
+    pub fn stopwords(&mut self, languages: &[Language]) -> &Self {
+        self.title.stopwords(languages);
+        self
+    }
+
+    /// Get the pipeline used to clean the title of the series, see [TitleCleaner::pipeline](struct.TitleCleaner.html#method.pipeline)
+    pub fn pipeline(&self) -> Pipeline {
+        self.title.pipeline()
+    }
+
+    /// Clean the title of the series with a custom [Pipeline](struct.Pipeline.html) instead of the preset one
+    pub fn clean_with(&mut self, pipeline: &Pipeline) -> &Self {
+        self.title.clean_with(pipeline);
+
+        let mut issn = IdentifierCleaner::new(&self.issn_tokens);
+        issn.clean();
+
+        self.issn = issn.identifiers().iter().find(|identifier| identifier.kind() == IdentifierKind::Issn).cloned();
+        self.numbering = self.title.numbering().clone();
+
+        for part in &self.numbering_tokens {
+            let (rest, numbering) = split_off_numbering(part.clone());
+
+            if !numbering.is_empty() {
+                self.numbering.extend(numbering);
+                continue;
+            }
+
+            let words : Vec<&Cow<str>> = rest.iter().filter(|token| !token.chars().all(is_punctuation)).collect();
+
+            if let [word] = words.as_slice() {
+                self.numbering.extend(parse_number(word, true).map(|number| Numbering::new(Designation::Unspecified, number)));
+            }
+        }
+
+        self
+    }
+
+    /// Build a key out of the title of the series
+    pub fn key(&self) -> String {
+        self.title.tokens().join(" ")
+    }
+}
+
+impl <'a>Clean for SeriesCleaner<'a> {
+    fn clean(&mut self) -> &Self {
+        let pipeline = self.title.shared_pipeline();
+
+        self.clean_with(&pipeline)
+    }
+
+    #[cfg(feature = "stem")]
+    fn stem(&mut self, lang: Algorithm) -> &Self {
+        self.title.stem(lang);
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series(input: &str) -> (String, Option<String>, Vec<String>) {
+        let mut series = SeriesCleaner::from_str(input);
+        series.clean();
+
+        (series.key(), series.issn().map(Identifier::key), series.numbering().iter().map(Numbering::to_string).collect())
+    }
+
+    #[test]
+    fn test_series_statements() {
+        assert_eq!(series("(Que sais-je ? ; 1234)").2, vec!["1234"]);
+        assert_eq!(series("Lecture notes in computer science, ISSN 0302-9743 ; v. 1000"), ("lecture notes computer science".to_string(), Some("0302-9743".to_string()), vec!["volume 1000".to_string()]));
+        assert_eq!(series("Collection Folio. Histoire ; no 42").2, vec!["number 42"]);
+        assert_eq!(series("Bibliothèque de la Pléiade, t. II"), ("bibliotheque pleiade".to_string(), None, vec!["volume 2".to_string()]));
+        assert_eq!(series("Cahiers du cinéma, ISSN 0008-011X").1, Some("0008-011X".to_string()));
+        assert_eq!(series("Lecture notes in computer science, ISSN 0302-9742").1, None);
+        assert!(series("Points. Essais").2.is_empty());
+        let mut title = TitleCleaner::from_str("Chroniques de Paris, 1900-1908");
+        title.clean();
+
+        assert_eq!(series("Chroniques de Paris, 1900-1908 ; 2"), (title.tokens().join(" "), None, vec!["2".to_string()]));
+        assert_eq!(series("Collection 2049-3630 ; 3").1, Some("2049-3630".to_string()));
+    }
+}
@@ -0,0 +1,224 @@
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+use unidecode::unidecode;
+
+use crate::language::Language;
+use crate::pipeline::Step;
+
+const FRENCH: &[&str] = &[
+    "a", "à", "afin", "ai", "aie", "aient", "ainsi", "alors", "au", "aucun", "aucune", "auprès", "auquel", "aussi", "autre", "autres", "aux", "auxquelles", "auxquels",
+    "avec", "avoir", "c", "ça", "car", "ce", "ceci", "cela", "celle", "celles", "celui", "cependant", "ces", "cet", "cette", "ceux", "chacun", "chaque", "chez", "comme",
+    "comment", "contre", "d", "dans", "de", "depuis", "des", "desquelles", "desquels", "dès", "donc", "dont", "du", "duquel", "durant", "elle", "elles", "en", "encore",
+    "entre", "est", "et", "été", "être", "eu", "eux", "fait", "hors", "il", "ils", "j", "je", "jusqu", "jusque", "l", "la", "là", "laquelle", "le", "lequel", "les",
+    "lesquelles", "lesquels", "leur", "leurs", "lors", "lui", "m", "ma", "mais", "me", "même", "mêmes", "mes", "moi", "mon", "n", "ne", "ni", "nos", "notre", "nous",
+    "on", "ont", "ou", "où", "par", "parmi", "pas", "pendant", "peu", "plus", "pour", "pourquoi", "qu", "quand", "que", "quel", "quelle", "quelles", "quels", "qui",
+    "quoi", "s", "sa", "sans", "se", "selon", "ses", "si", "sien", "sienne", "son", "sont", "sous", "sur", "t", "ta", "te", "tes", "toi", "ton", "tous", "tout",
+    "toute", "toutes", "très", "tu", "un", "une", "unes", "uns", "vers", "voici", "voilà", "vos", "votre", "vous", "y"
+];
+
+const ENGLISH: &[&str] = &[
+    "a", "about", "above", "after", "again", "against", "all", "am", "an", "and", "any", "are", "as", "at", "be", "because", "been", "before", "being", "below",
+    "between", "both", "but", "by", "can", "could", "did", "do", "does", "doing", "down", "during", "each", "few", "for", "from", "further", "had", "has", "have",
+    "having", "he", "her", "here", "hers", "herself", "him", "himself", "his", "how", "i", "if", "in", "into", "is", "it", "its", "itself", "just", "me", "more",
+    "most", "my", "myself", "no", "nor", "not", "of", "off", "on", "once", "only", "or", "other", "our", "ours", "ourselves", "out", "over", "own", "same", "she",
+    "should", "so", "some", "such", "than", "that", "the", "their", "theirs", "them", "themselves", "then", "there", "these", "they", "this", "those", "through",
+    "to", "too", "under", "until", "up", "upon", "very", "was", "we", "were", "what", "when", "where", "which", "while", "who", "whom", "why", "will", "with",
+    "within", "without", "would", "you", "your", "yours", "yourself", "yourselves"
+];
+
+const GERMAN: &[&str] = &[
+    "aber", "alle", "allem", "allen", "aller", "alles", "als", "also", "am", "an", "ander", "andere", "anderem", "anderen", "anderer", "anderes", "auch", "auf",
+    "aus", "bei", "beim", "bin", "bis", "bist", "da", "damit", "dann", "das", "dass", "daß", "dem", "den", "denn", "der", "des", "dessen", "die", "dies", "diese",
+    "diesem", "diesen", "dieser", "dieses", "doch", "dort", "du", "durch", "ein", "eine", "einem", "einen", "einer", "eines", "er", "es", "für", "gegen", "hat",
+    "hatte", "ich", "ihm", "ihn", "ihr", "ihre", "ihrem", "ihren", "ihrer", "im", "in", "ins", "ist", "jede", "jedem", "jeden", "jeder", "jedes", "kein", "keine",
+    "man", "mein", "meine", "mit", "nach", "nicht", "noch", "nun", "nur", "ob", "oder", "ohne", "sein", "seine", "seinem", "seinen", "seiner", "sich", "sie",
+    "sind", "so", "über", "um", "und", "uns", "unser", "unter", "vom", "von", "vor", "während", "war", "waren", "was", "weil", "welche", "welchem", "welchen",
+    "welcher", "welches", "wenn", "wer", "werden", "wie", "wir", "wird", "zu", "zum", "zur", "zwischen"
+];
+
+const SPANISH: &[&str] = &[
+    "a", "al", "algo", "algunas", "algunos", "ante", "antes", "como", "con", "contra", "cual", "cuando", "de", "del", "desde", "donde", "durante", "e", "el", "él",
+    "ella", "ellas", "ellos", "en", "entre", "era", "es", "esa", "esas", "ese", "eso", "esos", "esta", "está", "estas", "este", "esto", "estos", "fue", "ha", "hasta",
+    "hay", "la", "las", "le", "les", "lo", "los", "más", "me", "mi", "mis", "mucho", "muy", "nada", "ni", "no", "nos", "nosotros", "o", "os", "otra", "otras", "otro",
+    "otros", "para", "pero", "poco", "por", "porque", "que", "qué", "quien", "quienes", "se", "sea", "según", "ser", "si", "sí", "sin", "sobre", "son", "su", "sus",
+    "también", "tanto", "te", "tiene", "todo", "todos", "tu", "tus", "un", "una", "unas", "uno", "unos", "y", "ya", "yo"
+];
+
+const ITALIAN: &[&str] = &[
+    "a", "ad", "agli", "ai", "al", "all", "alla", "alle", "allo", "anche", "che", "chi", "ci", "coi", "col", "come", "con", "contro", "cui", "da", "dagli", "dai", "dal",
+    "dall", "dalla", "dalle", "dallo", "degli", "dei", "del", "dell", "della", "delle", "dello", "di", "dove", "e", "è", "ed", "essere", "fra", "gli", "i", "il", "in", "io", "l", "la",
+    "le", "lei", "lo", "loro", "lui", "ma", "mi", "mia", "mie", "miei", "mio", "ne", "negli", "nei", "nel", "nell", "nella", "nelle", "nello", "noi", "non", "o", "per",
+    "perché", "più", "quale", "quanto", "quella", "quelle", "quelli", "quello", "questa", "queste", "questi", "questo", "se", "sei", "si", "sia", "sono", "su",
+    "sua", "sue", "sugli", "sui", "sul", "sull", "sulla", "sulle", "sullo", "suo", "suoi", "tra", "tu", "tua", "tue", "tuo", "tuoi", "un", "una", "uno", "voi"
+];
+
+impl Language {
+    /// Bundled stopword list of the language, empty for the languages which only provide particles (Dutch, Portuguese, Arabic and the Scandinavian languages)
+    ///
+    /// ```
+    /// # use b_cleaner::Language;
+    /// # fn main() {
+    /// assert!(Language::French.stopwords().contains(&"dans"));
+    /// # }
+    /// ```
+    pub fn stopwords(&self) -> &'static [&'static str] {
+        match self {
+            Language::French => FRENCH,
+            Language::English => ENGLISH,
+            Language::German => GERMAN,
+            Language::Spanish => SPANISH,
+            Language::Italian => ITALIAN,
+            Language::Dutch | Language::Portuguese | Language::Arabic | Language::Danish | Language::Norwegian | Language::Swedish => &[]
+        }
+    }
+}
+
+/// Normalizes a word the same way the cleaners normalize tokens (lowercase and unidecode)
+pub(crate) fn normalize_word(word: &str) -> String {
+    unidecode(&word.to_lowercase())
+}
+
+/// Removes stopwords from a list of tokens
+///
+/// Stopwords are normalized (lowercase, unidecode), the step should be applied to normalized tokens.
+///
+/// ```
+/// # use std::borrow::Cow;
+/// # use b_cleaner::{RemoveStopwords, Language, Step};
+/// # fn main() {
+/// let mut stopwords = RemoveStopwords::new(&[Language::French, Language::English]);
+/// stopwords.add_words(&["lorem"]);
+///
+/// let mut tokens = vec![Cow::Borrowed("lorem"), Cow::Borrowed("about"), Cow::Borrowed("ipsum"), Cow::Borrowed("dans")];
+/// stopwords.apply(&mut tokens);
+///
+/// assert_eq!(tokens, vec!["ipsum"]);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RemoveStopwords {
+    words: HashSet<String>
+}
+
+impl RemoveStopwords {
+    /// Create a new RemoveStopwords step with the bundled lists of some languages
+    pub fn new(languages: &[Language]) -> Self {
+        let mut stopwords = RemoveStopwords::default();
+
+        languages.iter().for_each(|language| {
+            stopwords.add_words(language.stopwords());
+        });
+
+        stopwords
+    }
+
+    /// Create a new RemoveStopwords step from a user supplied list
+    pub fn from_words<S: AsRef<str>>(words: &[S]) -> Self {
+        let mut stopwords = RemoveStopwords::default();
+        stopwords.add_words(words);
+
+        stopwords
+    }
+
+    /// Add words to the stopword list
+    pub fn add_words<S: AsRef<str>>(&mut self, words: &[S]) -> &mut Self {
+        self.words.extend(words.iter().map(|word| normalize_word(word.as_ref())));
+        self
+    }
+
+    /// Returns true if a normalized token is a stopword
+    pub fn contains(&self, token: &str) -> bool {
+        self.words.contains(token)
+    }
+}
+
+impl Step for RemoveStopwords {
+    fn name(&self) -> &str {
+        "remove_stopwords"
+    }
+
+    fn apply<'a>(&self, tokens: &mut Vec<Cow<'a, str>>) {
+        tokens.retain(|token| !self.words.contains(token.as_ref()));
+    }
+}
+
+/// Splits elided stopwords off the following word (`l'histoire`, `dell'arte`), so they can be removed by [RemoveStopwords](struct.RemoveStopwords.html)
+///
+/// Only elisions which are stopwords are split (`aujourd'hui` is kept whole), the step must be applied before punctuation removal.
+///
+/// ```
+/// # use std::borrow::Cow;
+/// # use b_cleaner::{RemoveStopwords, SplitElisions, Language, Step};
+/// # fn main() {
+/// let stopwords = RemoveStopwords::new(&[Language::French]);
+///
+/// let mut tokens = vec![Cow::Borrowed("l'histoire"), Cow::Borrowed("d'aujourd'hui")];
+/// SplitElisions::new(&stopwords).apply(&mut tokens);
+///
+/// assert_eq!(tokens, vec!["l'", "histoire", "d'", "aujourd'hui"]);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SplitElisions {
+    stopwords: RemoveStopwords
+}
+
+impl SplitElisions {
+    /// Create a new SplitElisions step, elisions are looked up in the stopwords
+    pub fn new(stopwords: &RemoveStopwords) -> Self {
+        SplitElisions {
+            stopwords: stopwords.clone()
+        }
+    }
+}
+
+impl Step for SplitElisions {
+    fn name(&self) -> &str {
+        "split_elisions"
+    }
+
+    fn apply<'a>(&self, tokens: &mut Vec<Cow<'a, str>>) {
+        let mut split = Vec::with_capacity(tokens.len());
+
+        for token in tokens.drain(..) {
+            let elision = token.char_indices()
+                .find(|(_, c)| *c == '\'' || *c == '’')
+                .filter(|(index, c)| index + c.len_utf8() < token.len() && self.stopwords.contains(&normalize_word(&token[..*index])))
+                .map(|(index, c)| index + c.len_utf8());
+
+            match (elision, token) {
+                (Some(end), Cow::Borrowed(token)) => split.extend([Cow::Borrowed(&token[..end]), Cow::Borrowed(&token[end..])]),
+                (Some(end), Cow::Owned(token)) => split.extend([Cow::Owned(token[..end].to_string()), Cow::Owned(token[end..].to_string())]),
+                (None, token) => split.push(token)
+            }
+        }
+
+        *tokens = split;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_lists_are_normalized() {
+        let stopwords = RemoveStopwords::new(&Language::ALL);
+
+        assert!(stopwords.contains("a"));
+        assert!(stopwords.contains("tres"));
+        assert!(stopwords.contains("fur"));
+        assert!(!stopwords.contains("très"));
+    }
+
+    #[test]
+    fn test_split_elisions() {
+        let stopwords = RemoveStopwords::new(&[Language::Italian]);
+
+        let mut tokens = vec![Cow::Borrowed("Dell’arte"), Cow::Owned("all'opera".to_string()), Cow::Borrowed("d'oro"), Cow::Borrowed("l'")];
+        SplitElisions::new(&stopwords).apply(&mut tokens);
+
+        assert_eq!(tokens, vec!["Dell’", "arte", "all'", "opera", "d'oro", "l'"]);
+    }
+}
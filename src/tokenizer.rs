@@ -0,0 +1,161 @@
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+/// Returns true if a char is considered as a punctuation mark by the tokenizer
+///
+/// ASCII punctuation and the most common typographic punctuation marks (french quotes, curly quotes, dashes, ellipsis, etc.) are recognized.
+///
+/// ```
+/// # use b_cleaner::is_punctuation;
+/// # fn main() {
+/// assert!(is_punctuation(':'));
+/// assert!(is_punctuation('«'));
+/// assert!(!is_punctuation('a'));
+/// # }
+/// ```
+pub fn is_punctuation(c: char) -> bool {
+    c.is_ascii_punctuation() || matches!(c, '«' | '»' | '‹' | '›' | '“' | '”' | '„' | '‘' | '’' | '‚' | '…' | '–' | '—' | '¿' | '¡' | '·')
+}
+
+/// An iterator over the tokens of a string
+///
+/// The input is split on Unicode white spaces. Leading and trailing punctuation marks are then detached from words and yielded as separated tokens,
+/// punctuation marks inside a word (`Catch-22`, `l'amour`, `S.l`) are kept. A run of the same punctuation mark (`...`, `--`) is yielded as a single token.
+///
+/// Tokens are borrowed from the input, no allocation is made.
+///
+/// ```
+/// # use b_cleaner::Tokenizer;
+/// # fn main() {
+/// let tokens : Vec<&str> = Tokenizer::new("Lorem ipsum dolor: sit (amet)").collect();
+///
+/// assert_eq!(tokens, vec!["Lorem", "ipsum", "dolor", ":", "sit", "(", "amet", ")"]);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Tokenizer<'a> {
+    input: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+    word_end: usize,
+}
+
+impl <'a>Tokenizer<'a> {
+    /// Create a new Tokenizer
+    ///
+    /// ```
+    /// # use b_cleaner::Tokenizer;
+    /// # fn main() {
+    /// let mut tokenizer = Tokenizer::new("Lorem ipsum");
+    ///
+    /// assert_eq!(tokenizer.next(), Some("Lorem"));
+    /// # }
+    /// ```
+    pub fn new(input: &'a str) -> Self {
+        Tokenizer {
+            input,
+            chars: input.char_indices().peekable(),
+            word_end: 0,
+        }
+    }
+
+    /// Returns the byte index of the end of the core of the word starting at `start`, trailing punctuation excluded
+    fn core_end(&self, start: usize) -> usize {
+        let word = &self.input[start..self.word_end];
+
+        word.char_indices()
+            .rev()
+            .find(|(_, c)| !is_punctuation(*c))
+            .map(|(index, c)| start + index + c.len_utf8())
+            .unwrap_or(start)
+    }
+
+    /// Consumes a run of the same punctuation mark and returns its end index
+    fn punctuation_run(&mut self, mark: char, start: usize) -> usize {
+        let mut end = start + mark.len_utf8();
+
+        while let Some(&(index, c)) = self.chars.peek() {
+            if c != mark || index >= self.word_end {
+                break;
+            }
+
+            end = index + c.len_utf8();
+            self.chars.next();
+        }
+
+        end
+    }
+}
+
+impl <'a>Iterator for Tokenizer<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        // Skip white spaces between words and find the end of the next word
+        while let Some(&(index, c)) = self.chars.peek() {
+            if index < self.word_end || !c.is_whitespace() {
+                break;
+            }
+
+            self.chars.next();
+        }
+
+        let (start, first) = self.chars.next()?;
+
+        if start >= self.word_end {
+            self.word_end = self.input[start..]
+                .find(char::is_whitespace)
+                .map(|offset| start + offset)
+                .unwrap_or_else(|| self.input.len());
+        }
+
+        if is_punctuation(first) {
+            let end = self.punctuation_run(first, start);
+            return Some(&self.input[start..end]);
+        }
+
+        let end = self.core_end(start);
+
+        while let Some(&(index, _)) = self.chars.peek() {
+            if index >= end {
+                break;
+            }
+
+            self.chars.next();
+        }
+
+        Some(&self.input[start..end])
+    }
+}
+
+/// Splits a string into tokens using the [Tokenizer](struct.Tokenizer.html)
+///
+/// ```
+/// # use b_cleaner::tokenize;
+/// # fn main() {
+/// assert_eq!(tokenize("John W. Doe (1950-2018)"), vec!["John", "W", ".", "Doe", "(", "1950-2018", ")"]);
+/// assert_eq!(tokenize("Catch-22... l'amour ?!"), vec!["Catch-22", "...", "l'amour", "?", "!"]);
+/// # }
+/// ```
+pub fn tokenize(input: &str) -> Vec<&str> {
+    Tokenizer::new(input).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize_unicode_whitespace() {
+        assert_eq!(tokenize("Lorem\u{a0}ipsum\tdolor\u{2009}sit"), vec!["Lorem", "ipsum", "dolor", "sit"]);
+        assert_eq!(tokenize("   "), Vec::<&str>::new());
+        assert_eq!(tokenize(""), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_tokenize_punctuation() {
+        assert_eq!(tokenize("dolor: sit"), tokenize("dolor : sit"));
+        assert_eq!(tokenize("«Les Misérables»"), vec!["«", "Les", "Misérables", "»"]);
+        assert_eq!(tokenize("[S.l.]"), vec!["[", "S.l", ".", "]"]);
+        assert_eq!(tokenize("-- :"), vec!["--", ":"]);
+    }
+}